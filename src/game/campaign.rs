@@ -0,0 +1,158 @@
+use std::fs::File;
+use std::io::Write;
+use std::io::Read;
+use std::path::Path;
+use std::string::String;
+
+use game::datafile;
+use game::game::POSSIBLE_CHARACTER_TILES;
+use game::loot;
+use game::map;
+use game::traps;
+use game::vehicle;
+
+pub static CAMPAIGN_DATA: &str = "assets/campaign.txt";
+pub static PROGRESS_FILE: &str = "campaign_progress.txt";
+
+/// One level of the campaign, in the order they are played.
+pub struct CampaignLevel {
+	pub name:     String,
+	/// Layout handed to `Map::load_map`.
+	pub map:      map::Layout,
+	pub time:     i32,
+	/// Index into the vehicle definitions.
+	pub vehicle:  usize,
+	/// Relative weights of slow, crazy, random and cloud zombies.
+	pub zombies:  [u32; 4],
+	/// Name of the powerup loot table, see `LootTable::named`.
+	pub powerups: String,
+	/// Traps set at fixed tiles as kind, column and row. Levels listing
+	/// none get randomly scattered traps instead.
+	pub traps:    Vec<(i32, u32, u32)>,
+	pub intro:    Vec<String>
+}
+
+/// Reads the campaign from the data file at `path`, checking that every
+/// vehicle and powerup table it names is one of `vehicles` and `loot_tables`.
+///
+/// Same format as the vehicle data: `keyword values...` lines with `#`
+/// starting a comment, and a broken file stops the game with a message
+/// saying what is wrong.
+pub fn load_campaign(path: &str,
+                     vehicles: &[vehicle::VehicleDefinition],
+                     loot_tables: &[loot::LootTable]) -> Vec<CampaignLevel> {
+	let mut levels: Vec<CampaignLevel> = Vec::new();
+	datafile::read_lines(path, "campaign", |line| {
+		let rest = &line.rest;
+		if line.keyword == "level" {
+			levels.push(CampaignLevel {
+				name:     rest.join(" "),
+				map:      map::Layout::Open,
+				time:     0,
+				vehicle:  0,
+				zombies:  [1, 1, 1, 1],
+				powerups: String::from("standard"),
+				traps:    Vec::new(),
+				intro:    Vec::new()
+			});
+			return;
+		}
+		let level = line.block(&mut levels, "level");
+
+		match line.keyword {
+			"map" => {
+				level.map = match rest.first() {
+					Some(&"open")  => map::Layout::Open,
+					Some(&"walls") => map::Layout::Scattered,
+					Some(path)     => map::load_layout(path),
+					None           => line.error("map should be open, walls or a layout file")
+				};
+			},
+			"time" => {
+				level.time = match rest.first().map(|word| word.parse::<i32>()) {
+					Some(Ok(time)) if time > 0 => time,
+					_                          => line.error("expected a time above zero")
+				};
+			},
+			"vehicle" => {
+				let name = rest.join(" ");
+				level.vehicle = match vehicles.iter().position(|definition| definition.name == name) {
+					Some(index) => index,
+					None        => line.error("unknown vehicle")
+				};
+			},
+			"zombies" => {
+				let weights: Vec<u32> = rest.iter().map(|word| match word.parse::<u32>() {
+					Ok(weight) => weight,
+					Err(_)     => line.error("expected a number")
+				}).collect();
+				if weights.len() != 4 {
+					line.error("wrong number of values");
+				}
+				if weights.iter().sum::<u32>() == 0 {
+					line.error("the zombie mix needs at least one zombie");
+				}
+				level.zombies = [weights[0], weights[1], weights[2], weights[3]];
+			},
+			"powerups" => {
+				let name = rest.join(" ");
+				if loot::LootTable::named(loot_tables, &name).is_none() {
+					line.error("unknown powerup table");
+				}
+				level.powerups = name;
+			},
+			"trap" => {
+				if rest.len() != 3 {
+					line.error("expected a trap kind, column and row");
+				}
+				let kind = match traps::kind_named(rest[0]) {
+					Some(kind) => kind,
+					None       => line.error("unknown trap")
+				};
+				let tiles: Vec<u32> = rest[1..].iter().map(|word| match word.parse::<u32>() {
+					Ok(tile) if tile >= 1 && tile < POSSIBLE_CHARACTER_TILES => tile,
+					_                                                          => line.error("trap should be inside the map")
+				}).collect();
+				level.traps.push((kind, tiles[0], tiles[1]));
+			},
+			"intro" => { level.intro.push(rest.join(" ")); },
+			_ => line.error("unknown keyword")
+		}
+	});
+
+	for level in levels.iter() {
+		if level.time == 0 {
+			panic!("{}: level {} has no time limit", path, level.name);
+		}
+	}
+	if levels.is_empty() {
+		panic!("{}: no levels defined", path);
+	}
+	levels
+}
+
+/// How many campaign levels have been unlocked, always at least the first.
+pub fn load_progress() -> usize {
+	match File::open(Path::new(PROGRESS_FILE)) {
+		Ok(file) => {
+			let mut progress = String::new();
+			let mut f = file;
+			f.read_to_string(&mut progress).unwrap();
+			match progress.trim().parse::<usize>() {
+				Ok(unlocked) if unlocked > 0 => unlocked,
+				_ => { println!("ignoring campaign progress: {}", progress); 1 }
+			}
+		},
+		Err(e) => { println!("failed to get campaign progress: {}", e); 1 }
+	}
+}
+
+pub fn store_progress(unlocked: usize) {
+	match File::create(Path::new(PROGRESS_FILE)) {
+		Ok(file) => {
+			let mut f = file;
+			f.write_all(unlocked.to_string().as_bytes()).unwrap();
+		},
+		Err(e) => println!("failed to write campaign progress: {}", e)
+	}
+}
@@ -4,7 +4,9 @@ use rand::Rng;
 use std::collections::HashMap;
 
 use game::graphics;
+use game::health;
 use game::sprite;
+use game::status;
 
 use game::collisions::{Info,Rectangle};
 use game::map;
@@ -54,9 +56,12 @@ pub struct Character {
 	pub accel_y:       i32, 
 	pub target_x:      units::Game, 
 	pub target_y:      units::Game,
+	pub investigate_timer: i32,
 
 	// flags
-	pub killed: i32
+	pub killed: i32,
+	pub health: health::Health,
+	pub effects: status::StatusEffects
 }
 
 impl Character {
@@ -88,8 +93,11 @@ impl Character {
 			accel_y: 0, 
 			target_x: x, 
 			target_y: y, 
+			investigate_timer: 0,
 
-			killed: -1
+			killed: -1,
+			health: health::Health::new(1, 0),
+			effects: status::StatusEffects::new()
 		};
 
 		new_character
@@ -133,28 +141,54 @@ impl Character {
 		self.killed = 5;
 	}
 
+	pub fn revive_character(&mut self) {
+		self.killed = -1;
+	}
+
 	pub fn is_killed(&self) -> bool {
 		self.killed >= 0
 	}
 
+	/// Applies `damage` to this character's health and replaces the
+	/// character's velocity with the knockback. Ignored hits do not push the
+	/// character around.
+	pub fn take_damage(&mut self, damage: &health::Damage) -> health::DamageResult {
+		let result = self.health.take_damage(damage);
+		let (knockback_x, knockback_y) = damage.knockback;
+		if result != health::DamageResult::Ignored && (knockback_x != units::Velocity(0.0) || knockback_y != units::Velocity(0.0)) {
+			self.velocity_x = knockback_x;
+			self.velocity_y = knockback_y;
+		}
+		result
+	}
+
 	pub fn set_facing(&mut self, direction: sprite::Facing) {
 		let (last_action, _) = self.movement;
 		self.movement = (last_action, direction);
 	}
 
 	pub fn update_x(&mut self, map: &map::Map, acceleration: units::Acceleration, max_velocity: units::Velocity) {
+		// status effects change how fast and which way the character goes
+		let (acceleration, max_velocity) = self.effects.adjust_movement(acceleration, max_velocity);
+		let direction = self.effects.steer(self.accel_x);
+		if self.effects.is_immobile() {
+			self.velocity_x = units::Velocity(0.0);
+		}
+
 		// compute next velocity
-		let accel_x: units::Acceleration = if self.accel_x < 0  {
+		let accel_x: units::Acceleration = if self.effects.is_immobile() {
+			units::Acceleration(0.0)
+		} else if direction < 0  {
 			-acceleration
-		} else if self.accel_x > 0 {
+		} else if direction > 0 {
 		    acceleration
 		} else { units::Acceleration(0.0) };
 
 		self.velocity_x = self.velocity_x + (accel_x * self.elapsed_time);
 
-		if self.accel_x < 0 {
+		if direction < 0 {
 			self.velocity_x = units::max(self.velocity_x, -max_velocity);
-		} else if self.accel_x > 0 {
+		} else if direction > 0 {
 			self.velocity_x = units::min(self.velocity_x, max_velocity);
 		} 
 
@@ -205,18 +239,27 @@ impl Character {
 	}
 
 	pub fn update_y (&mut self, map: &map::Map, acceleration: units::Acceleration, max_velocity: units::Velocity) {
+		// status effects change how fast and which way the character goes
+		let (acceleration, max_velocity) = self.effects.adjust_movement(acceleration, max_velocity);
+		let direction = self.effects.steer(self.accel_y);
+		if self.effects.is_immobile() {
+			self.velocity_y = units::Velocity(0.0);
+		}
+
 		// compute next velocity
-		let accel_y: units::Acceleration = if self.accel_y < 0  {
+		let accel_y: units::Acceleration = if self.effects.is_immobile() {
+			units::Acceleration(0.0)
+		} else if direction < 0  {
 			-acceleration 
-		} else if self.accel_y > 0 {
+		} else if direction > 0 {
 		    acceleration
 		} else { units::Acceleration(0.0) };
 
 		self.velocity_y = self.velocity_y + (accel_y * self.elapsed_time);
 
-		if self.accel_y < 0 {
+		if direction < 0 {
 			self.velocity_y = units::max(self.velocity_y, -max_velocity);
-		} else if self.accel_y > 0 {
+		} else if direction > 0 {
 			self.velocity_y = units::min(self.velocity_y, max_velocity);
 		} 
 
@@ -290,6 +333,37 @@ impl Character {
 		}
 	}
 
+	/// Moves the character the shortest way out of `rectangle`, stopping it
+	/// along that axis. Used for obstacles that are not part of the map.
+	pub fn push_out_of(&mut self, rectangle: &Rectangle) {
+		let own = self.damage_rectangle();
+		if !own.collides_with(rectangle) {
+			return;
+		}
+
+		let units::Game(left)  = own.right() - rectangle.left();
+		let units::Game(right) = rectangle.right() - own.left();
+		let units::Game(up)    = own.bottom() - rectangle.top();
+		let units::Game(down)  = rectangle.bottom() - own.top();
+		let shortest = left.min(right).min(up).min(down);
+
+		if shortest == left {
+			self.map_x = self.map_x - units::Game(left);
+			self.velocity_x = units::Velocity(0.0);
+		} else if shortest == right {
+			self.map_x = self.map_x + units::Game(right);
+			self.velocity_x = units::Velocity(0.0);
+		} else if shortest == up {
+			self.map_y = self.map_y - units::Game(up);
+			self.velocity_y = units::Velocity(0.0);
+		} else {
+			self.map_y = self.map_y + units::Game(down);
+			self.velocity_y = units::Velocity(0.0);
+		}
+		self.x = self.map_x % SCREEN_CORRECTION.to_game();
+		self.y = self.map_y % SCREEN_CORRECTION.to_game();
+	}
+
 	pub fn center_x(&self) -> units::Game {
 		self.x + units::HalfTile(1)
 	}
@@ -412,6 +486,24 @@ impl Character {
 		}
 	}
 
+	/// Heads for `x`, `y` for the next `frames` updates, or until it is reached.
+	pub fn investigate(&mut self, x: units::Game, y: units::Game, frames: i32) {
+		self.target_x = x;
+		self.target_y = y;
+		self.investigate_timer = frames;
+	}
+
+	/// Counts down an ongoing investigation, returns whether it is still going.
+	pub fn update_investigation(&mut self) -> bool {
+		if self.investigate_timer > 0 {
+			self.investigate_timer -= 1;
+			if self.distance( self.target_x, self.target_y ) < 20.0 {
+				self.investigate_timer = 0;
+			}
+		}
+		self.investigate_timer > 0
+	}
+
 	pub fn set_new_random_target(&mut self) {
 		let mut rng = rand::thread_rng();
 		let distance_to_target = self.distance( self.target_x, self.target_y );
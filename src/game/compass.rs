@@ -0,0 +1,155 @@
+use game::graphics;
+use game::map;
+use game::game::{SCREEN_WIDTH, SCREEN_HEIGHT};
+
+use game::units;
+use game::units::{AsGame,AsPixel};
+
+use sdl2::rect;
+
+// arrows sit this far in from the edge of the screen (in pixels)
+static EDGE_MARGIN:   f64 = 24.0;
+static ARROW_SIZE:    u32 = 14;
+// distance labels, a few pixels wide per digit
+static LABEL_OFFSET:  f64 = 18.0;
+static LABEL_HEIGHT:  u32 = 14;
+static DIGIT_WIDTH:   u32 = 8;
+
+/// Something worth pointing the player towards.
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum Objective {
+	/// A loose vehicle part.
+	Part,
+	/// The vehicle, when there is a part to hand over.
+	Vehicle,
+	/// The vehicle while zombies are pulling it apart.
+	VehicleUnderAttack,
+	/// The edge of the map to drive the finished vehicle to.
+	Extraction,
+	Powerup
+}
+
+/// How arrows for one kind of objective are shown.
+#[derive(Clone,Copy)]
+pub struct CompassStyle {
+	pub objective:     Objective,
+	pub enabled:       bool,
+	pub color:         (u8, u8, u8),
+	/// Only the closest objective of the kind gets an arrow.
+	pub nearest_only:  bool,
+	pub show_distance: bool,
+	/// Objectives further away than this are left out, zero for no limit.
+	pub range:         units::Tile
+}
+
+pub static STYLES: [CompassStyle; 5] = [
+	CompassStyle { objective: Objective::Part,               enabled: true,  color: (240, 200, 40),  nearest_only: true,  show_distance: true,  range: units::Tile(0) },
+	CompassStyle { objective: Objective::Vehicle,            enabled: true,  color: (40, 200, 240),  nearest_only: true,  show_distance: true,  range: units::Tile(0) },
+	CompassStyle { objective: Objective::VehicleUnderAttack, enabled: true,  color: (240, 40, 40),   nearest_only: true,  show_distance: true,  range: units::Tile(0) },
+	CompassStyle { objective: Objective::Extraction,         enabled: true,  color: (40, 220, 120),  nearest_only: true,  show_distance: true,  range: units::Tile(0) },
+	CompassStyle { objective: Objective::Powerup,            enabled: false, color: (200, 200, 200), nearest_only: false, show_distance: false, range: units::Tile(15) }
+];
+
+/// Arrows at the edge of the screen pointing at objectives on other pages.
+///
+/// Each update the game hands over what is currently worth heading for,
+/// the compass works out which of those are off screen and draws an arrow
+/// (and optionally the distance in tiles) for each, styled by objective.
+pub struct Compass {
+	styles:  Vec<CompassStyle>,
+	targets: Vec<(Objective, units::Game, units::Game)>
+}
+
+impl Default for Compass {
+	fn default() -> Compass {
+		Compass::new()
+	}
+}
+
+impl Compass {
+	pub fn new() -> Compass {
+		Compass {
+			styles:  STYLES.to_vec(),
+			targets: Vec::new()
+		}
+	}
+
+	pub fn set_enabled(&mut self, objective: Objective, enabled: bool) {
+		for style in self.styles.iter_mut().filter(|style| style.objective == objective) {
+			style.enabled = enabled;
+		}
+	}
+
+	pub fn is_enabled(&self, objective: Objective) -> bool {
+		self.styles.iter().any(|style| style.objective == objective && style.enabled)
+	}
+
+	/// Forgets the objectives from the last update.
+	pub fn clear(&mut self) {
+		self.targets.clear();
+	}
+
+	/// Marks `x`, `y` on the map as an objective of kind `objective`.
+	pub fn add(&mut self, objective: Objective, x: units::Game, y: units::Game) {
+		if self.is_enabled(objective) {
+			self.targets.push((objective, x, y));
+		}
+	}
+
+	/// Draws an arrow for every off screen objective, as seen from `from`.
+	pub fn draw(&self, display: &mut graphics::Graphics, map: &map::Map, from: (units::Game, units::Game)) {
+		let (from_x, from_y) = from;
+		for style in self.styles.iter().filter(|style| style.enabled) {
+			let units::Game(range) = style.range.to_game();
+			let mut targets: Vec<(f64, units::Game, units::Game)> = self.targets.iter()
+				.filter(|&&(objective, x, y)| objective == style.objective && !map.on_screen(x, y))
+				.map(|&(_, x, y)| {
+					let (units::Game(dx), units::Game(dy)) = (x - from_x, y - from_y);
+					((dx * dx + dy * dy).sqrt(), x, y)
+				})
+				.filter(|&(distance, _, _)| range == 0.0 || distance <= range)
+				.collect();
+			targets.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+			if style.nearest_only {
+				targets.truncate(1);
+			}
+			for &(distance, x, y) in targets.iter() {
+				Compass::draw_arrow(display, map, style, from, (x, y), distance);
+			}
+		}
+	}
+
+	fn draw_arrow(display: &mut graphics::Graphics, map: &map::Map,
+	              style: &CompassStyle,
+	              from: (units::Game, units::Game), to: (units::Game, units::Game),
+	              distance: f64) {
+		let ((from_x, from_y), (to_x, to_y)) = (from, to);
+		let (units::Game(dx), units::Game(dy)) = (to_x - from_x, to_y - from_y);
+		if dx == 0.0 && dy == 0.0 {
+			return;
+		}
+
+		// follow the line from the player until it meets the margin
+		let (units::Pixel(width), units::Pixel(height)) =
+			(SCREEN_WIDTH.to_pixel(), SCREEN_HEIGHT.to_pixel());
+		let (width, height) = (width as f64, height as f64);
+		let units::Game(page_x) = units::Tile(map.get_page_x() * 20).to_game();
+		let units::Game(page_y) = units::Tile(map.get_page_y() * 20).to_game();
+		let (units::Game(start_x), units::Game(start_y)) = (from_x, from_y);
+		let (start_x, start_y) = (start_x - page_x, start_y - page_y);
+		let limit_x = if dx > 0.0 { (width - EDGE_MARGIN - start_x) / dx } else if dx < 0.0 { (EDGE_MARGIN - start_x) / dx } else { f64::MAX };
+		let limit_y = if dy > 0.0 { (height - EDGE_MARGIN - start_y) / dy } else if dy < 0.0 { (EDGE_MARGIN - start_y) / dy } else { f64::MAX };
+		let scale = limit_x.min(limit_y).max(0.0);
+		let (tip_x, tip_y) = (start_x + dx * scale, start_y + dy * scale);
+
+		display.draw_arrow((tip_x as i32, tip_y as i32), (dx, dy), ARROW_SIZE, style.color);
+		if style.show_distance {
+			let units::Game(tile) = units::Tile(1).to_game();
+			let label = ((distance / tile).round() as u32).to_string();
+			let length = (dx * dx + dy * dy).sqrt();
+			let label_x = tip_x - (dx / length) * LABEL_OFFSET - (label.len() as u32 * DIGIT_WIDTH) as f64 / 2.0;
+			let label_y = tip_y - (dy / length) * LABEL_OFFSET - LABEL_HEIGHT as f64 / 2.0;
+			display.draw_text(&label, rect::Rect::new(label_x as i32, label_y as i32, label.len() as u32 * DIGIT_WIDTH, LABEL_HEIGHT));
+		}
+	}
+}
@@ -0,0 +1,187 @@
+use std::fs::File;
+use std::io::Write;
+use std::io::Read;
+use std::path::Path;
+use std::string::String;
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use time;
+
+use game::leaderboard;
+
+pub static RESULTS_FILE: &str = "daily_leaderboard.txt";
+// director mix for crazy zombies only
+pub static CRAZY_MIX:    [u32; 4] = [0, 1, 0, 0];
+
+/// Twists on the usual rules, picked for the day.
+#[derive(Clone,Copy)]
+pub struct Modifiers {
+	/// Every zombie is a crazy one.
+	pub crazy_only:   bool,
+	/// No powerups on the map and nothing dropped by zombies.
+	pub no_powerups:  bool,
+	/// The game runs two updates for every frame.
+	pub double_speed: bool
+}
+
+impl Modifiers {
+	pub fn none() -> Modifiers {
+		Modifiers { crazy_only: false, no_powerups: false, double_speed: false }
+	}
+
+	pub fn describe(&self) -> String {
+		let mut names: Vec<&str> = Vec::new();
+		if self.crazy_only   { names.push("CRAZY ZOMBIES ONLY"); }
+		if self.no_powerups  { names.push("NO POWERUPS"); }
+		if self.double_speed { names.push("DOUBLE SPEED"); }
+		names.join(", ")
+	}
+}
+
+/// The challenge for one day. Everybody playing on the same (UTC) date
+/// gets the same seed and so the same level and modifiers.
+pub struct Challenge {
+	pub date:      String,
+	pub seed:      u64,
+	pub modifiers: Modifiers
+}
+
+impl Challenge {
+	pub fn today() -> Challenge {
+		let today = time::OffsetDateTime::now_utc().date();
+		Challenge::for_date(today.year(), today.month() as u8, today.day())
+	}
+
+	pub fn for_date(year: i32, month: u8, day: u8) -> Challenge {
+		let date = format!("{:04}-{:02}-{:02}", year, month, day);
+
+		// spread neighbouring dates out so their levels look nothing alike
+		let mut seed = (year as u64 * 10000) + (month as u64 * 100) + day as u64;
+		seed = (seed ^ (seed >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		seed = (seed ^ (seed >> 27)).wrapping_mul(0x94d049bb133111eb);
+		seed = seed ^ (seed >> 31);
+
+		// every day has at least one modifier
+		let mut rng = StdRng::seed_from_u64(seed);
+		let mut modifiers = Modifiers {
+			crazy_only:   rng.gen_range(0u32..3u32) == 0,
+			no_powerups:  rng.gen_range(0u32..3u32) == 0,
+			double_speed: rng.gen_range(0u32..3u32) == 0
+		};
+		if !modifiers.crazy_only && !modifiers.no_powerups && !modifiers.double_speed {
+			match rng.gen_range(0u32..3u32) {
+				0 => { modifiers.crazy_only = true; },
+				1 => { modifiers.no_powerups = true; },
+				_ => { modifiers.double_speed = true; }
+			}
+		}
+
+		Challenge {
+			date,
+			seed,
+			modifiers
+		}
+	}
+}
+
+/// The scored attempt for each day played, kept in a plain text file with
+/// one `date score frames kills` line per day, most recent last.
+pub struct DailyResults {
+	days: Vec<(String, leaderboard::Entry)>
+}
+
+impl DailyResults {
+	pub fn load() -> DailyResults {
+		let mut days = Vec::new();
+		match File::open(Path::new(RESULTS_FILE)) {
+			Ok(file) => {
+				let mut contents = String::new();
+				let mut f = file;
+				f.read_to_string(&mut contents).unwrap();
+				for line in contents.lines() {
+					let fields: Vec<&str> = line.split_whitespace().collect();
+					if fields.len() != 4 {
+						println!("skipping daily result: {}", line);
+						continue;
+					}
+					match (fields[1].parse::<i32>(), fields[2].parse::<i32>(), fields[3].parse::<u32>()) {
+						(Ok(score), Ok(frames), Ok(kills)) => {
+							days.push((String::from(fields[0]), leaderboard::Entry { score, frames, kills }));
+						},
+						_ => println!("skipping daily result: {}", line)
+					}
+				}
+			},
+			Err(e) => println!("failed to get daily results: {}", e)
+		}
+		DailyResults { days }
+	}
+
+	/// The scored attempt made on `date`, if any.
+	pub fn get(&self, date: &str) -> Option<leaderboard::Entry> {
+		self.days.iter().find(|(day, _)| day == date).map(|&(_, entry)| entry)
+	}
+
+	/// Stores the attempt for `date`, replacing any earlier one, and writes
+	/// the results back out.
+	pub fn record(&mut self, date: &str, entry: leaderboard::Entry) {
+		match self.days.iter().position(|(day, _)| day == date) {
+			Some(index) => { self.days[index].1 = entry; },
+			None        => { self.days.push((String::from(date), entry)); }
+		}
+		self.save();
+	}
+
+	/// The best score over every day played.
+	pub fn best(&self) -> i32 {
+		self.days.iter().map(|&(_, entry)| entry.score).max().unwrap_or(0)
+	}
+
+	fn save(&self) {
+		let mut contents = String::new();
+		for &(ref day, entry) in self.days.iter() {
+			contents = contents + day + " " + &entry.score.to_string() + " " + &entry.frames.to_string() + " " + &entry.kills.to_string() + "\n";
+		}
+		match File::create(Path::new(RESULTS_FILE)) {
+			Ok(file) => {
+				let mut f = file;
+				f.write_all(contents.as_bytes()).unwrap();
+			},
+			Err(e) => println!("failed to write daily results: {}", e)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use game::director;
+	use game::loot;
+
+	#[test]
+	fn challenge_is_fixed_for_a_date() {
+		let challenge = Challenge::for_date(2024, 1, 1);
+		assert_eq!(challenge.date, "2024-01-01");
+		assert_eq!(challenge.seed, 6314667812445730418);
+		assert_eq!(challenge.modifiers.describe(), "CRAZY ZOMBIES ONLY");
+		assert_eq!(Challenge::for_date(2024, 1, 1).seed, challenge.seed);
+		assert!(Challenge::for_date(2024, 1, 2).seed != challenge.seed);
+	}
+
+	/// The opening zombies and the level powerups rolled from `seed`.
+	fn roll_level(seed: u64) -> (Vec<u32>, Vec<u32>) {
+		let mut rng = StdRng::seed_from_u64(seed);
+		let zombies = director::Director::new().start_level(&mut rng, 1, director::DEFAULT_MIX);
+		let powerups = loot::LootTable::powerups(&loot::load_loot(loot::LOOT_DATA)).fill(&mut rng, 12, 1, loot::CALM);
+		(zombies, powerups)
+	}
+
+	#[test]
+	fn level_rolls_are_fixed_for_a_date() {
+		let seed = Challenge::for_date(2024, 1, 1).seed;
+		assert_eq!(roll_level(seed), roll_level(seed));
+		assert!(roll_level(seed) != roll_level(Challenge::for_date(2024, 1, 2).seed));
+	}
+}
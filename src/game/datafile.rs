@@ -0,0 +1,57 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::string::String;
+
+/// One `keyword values...` line of a data file.
+pub struct Line<'a> {
+	pub keyword: &'a str,
+	pub rest:    Vec<&'a str>,
+	path:        &'a str,
+	number:      usize
+}
+
+impl<'a> Line<'a> {
+	/// Stops the game with `msg`, pointing at this line.
+	pub fn error(&self, msg: &str) -> ! {
+		panic!("{} line {}: {}", self.path, self.number, msg)
+	}
+
+	/// The block this line belongs to, the last one started with `opener`.
+	pub fn block<'b, T>(&self, blocks: &'b mut [T], opener: &str) -> &'b mut T {
+		match blocks.last_mut() {
+			Some(block) => block,
+			None        => self.error(&format!("expected `{}` first", opener))
+		}
+	}
+}
+
+/// Hands every line of the data file at `path` to `handle`.
+///
+/// Anything after a `#` is ignored, as are blank lines. A missing file
+/// stops the game with a message naming `what` was being loaded.
+pub fn read_lines<F>(path: &str, what: &str, mut handle: F) where F: FnMut(&Line) {
+	let mut contents = String::new();
+	match File::open(Path::new(path)) {
+		Ok(mut f) => { f.read_to_string(&mut contents).unwrap(); },
+		Err(msg)  => { panic!("{} could not be loaded from {}: {}", what, path, msg) }
+	}
+
+	for (number, line) in contents.lines().enumerate() {
+		let line = match line.find('#') {
+			Some(index) => &line[..index],
+			None        => line
+		};
+		let mut words = line.split_whitespace();
+		let keyword = match words.next() {
+			Some(keyword) => keyword,
+			None          => { continue; }
+		};
+		handle(&Line {
+			keyword,
+			rest:   words.collect(),
+			path,
+			number: number + 1
+		});
+	}
+}
@@ -0,0 +1,276 @@
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use game::loot;
+use game::map;
+use game::game::MAX_ENEMIES;
+
+use game::units;
+use game::units::AsGame;
+
+// population caps
+static MAX_LEVEL_SPAWNS:    u32   = 120;
+static INITIAL_POPULATION:  (u32, u32) = (10, 20);
+
+// phase lengths (in updates)
+static BUILD_UP_FRAMES:     i32 = 1800;
+static PEAK_FRAMES:         i32 = 600;
+static RELAX_FRAMES:        i32 = 900;
+
+// spawn intervals (in updates)
+static BUILD_UP_INTERVAL:   i32 = 240;
+static PEAK_INTERVAL:       i32 = 60;
+static CALM_FRAMES:         i32 = 900;
+
+// stress model
+static PEAK_STRESS:         f64 = 80.0;
+static RELAX_STRESS:        f64 = 30.0;
+static HIT_STRESS:          f64 = 35.0;
+static KILL_STRESS:         f64 = 4.0;
+static LOW_HEALTH_STRESS:   f64 = 40.0;
+static STRESS_DECAY:        f64 = 0.05;
+static KILL_DECAY:          f64 = 0.995;
+static OBJECTIVE_RANGE:     units::Tile = units::Tile(6);
+
+// spawn area, kept clear of the border walls
+static MIN_SPAWN_TILE:      u32 = 2;
+static MAX_SPAWN_TILE:      u32 = 55;
+static OFF_CAMERA_DISTANCE: u32 = 2;
+
+// relative weights of slow, crazy, random and cloud zombies
+pub static DEFAULT_MIX:     [u32; 4] = [1, 1, 1, 1];
+
+#[derive(PartialEq,Clone,Copy)]
+pub enum Phase {
+	BuildUp,
+	Peak,
+	Relax
+}
+
+/// Paces zombie spawns over the course of a level.
+///
+/// The director keeps a running `stress` estimate for the player, fed by
+/// hits taken, recent kills and low health, and cycles between building up,
+/// peaking and relaxing. Waves are spawned just off-camera so they walk
+/// onto the screen rather than appearing on top of the player.
+pub struct Director {
+	phase:          Phase,
+	phase_timer:    i32,
+	spawn_timer:    i32,
+	stress:         f64,
+	recent_kills:   f64,
+	since_last_hit: i32,
+	spawned:        u32,
+	level:          i32,
+	mix:            [u32; 4]
+}
+
+impl Default for Director {
+	fn default() -> Director {
+		Director::new()
+	}
+}
+
+impl Director {
+	pub fn new() -> Director {
+		Director {
+			phase:          Phase::BuildUp,
+			phase_timer:    0,
+			spawn_timer:    BUILD_UP_INTERVAL,
+			stress:         0.0,
+			recent_kills:   0.0,
+			since_last_hit: 0,
+			spawned:        0,
+			level:          1,
+			mix:            DEFAULT_MIX
+		}
+	}
+
+	/// Resets the pacing for a new level and returns the kinds of zombies
+	/// that should already be roaming the map when the level starts. `mix`
+	/// weights how often each kind of zombie turns up.
+	///
+	/// Every roll the director makes comes from `rng`, so a level seeded
+	/// the same way gets the same zombies.
+	pub fn start_level(&mut self, rng: &mut StdRng, level: i32, mix: [u32; 4]) -> Vec<u32> {
+		*self = Director::new();
+		self.level = level;
+		self.mix = mix;
+
+		let (min, max) = INITIAL_POPULATION;
+		let mut kinds = Vec::new();
+		for _ in 0.. rng.gen_range(min..max) {
+			kinds.push(self.pick_kind(rng));
+		}
+		self.spawned = kinds.len() as u32;
+		kinds
+	}
+
+	/// Advances the director by one update and returns the kinds of zombies
+	/// to spawn this frame. `part_distance` is the distance from the player
+	/// to the closest remaining objective.
+	pub fn update(&mut self, rng: &mut StdRng, player_health: u32, alive: usize, part_distance: f64) -> Vec<u32> {
+		// update the stress estimate
+		self.since_last_hit += 1;
+		self.recent_kills *= KILL_DECAY;
+		self.stress -= STRESS_DECAY;
+		if player_health <= 1 && self.stress < LOW_HEALTH_STRESS {
+			self.stress = LOW_HEALTH_STRESS;
+		}
+		if self.stress < 0.0 {
+			self.stress = 0.0;
+		}
+
+		// move between phases
+		self.phase_timer += 1;
+		match self.phase {
+			Phase::BuildUp => {
+				if self.stress >= PEAK_STRESS || self.phase_timer >= BUILD_UP_FRAMES {
+					self.set_phase(Phase::Peak);
+				}
+			},
+			Phase::Peak => {
+				if self.phase_timer >= PEAK_FRAMES {
+					self.set_phase(Phase::Relax);
+				}
+			},
+			Phase::Relax => {
+				if self.phase_timer >= RELAX_FRAMES && self.stress < RELAX_STRESS {
+					self.set_phase(Phase::BuildUp);
+				}
+			}
+		}
+
+		let mut kinds = Vec::new();
+		if self.phase == Phase::Relax {
+			return kinds;
+		}
+
+		// count down to the next wave, faster when the player is comfortable
+		self.spawn_timer -= 1;
+		if self.since_last_hit > CALM_FRAMES {
+			self.spawn_timer -= 1;
+		}
+		let units::Game(objective_range) = OBJECTIVE_RANGE.to_game();
+		if part_distance < objective_range {
+			self.spawn_timer -= 1;
+		}
+		if self.spawn_timer > 0 {
+			return kinds;
+		}
+
+		let wave_size = match self.phase {
+			Phase::Peak => rng.gen_range(3u32..6u32) + self.level as u32,
+			_           => rng.gen_range(1u32..3u32) + (self.level as u32 / 2) + (self.recent_kills as u32 / 2)
+		};
+		self.spawn_timer = match self.phase {
+			Phase::Peak => PEAK_INTERVAL,
+			_           => BUILD_UP_INTERVAL
+		};
+
+		for _ in 0.. wave_size {
+			if !self.can_spawn(alive + kinds.len()) {
+				break;
+			}
+			kinds.push(self.pick_kind(rng));
+		}
+		self.spawned += kinds.len() as u32;
+		kinds
+	}
+
+	/// Whether another zombie may be added with `alive` zombies on the map.
+	pub fn can_spawn(&self, alive: usize) -> bool {
+		alive < MAX_ENEMIES as usize && self.spawned < MAX_LEVEL_SPAWNS
+	}
+
+	/// How hard the game is on the player right now, as a loot difficulty.
+	pub fn difficulty(&self) -> usize {
+		if self.stress >= PEAK_STRESS {
+			loot::INTENSE
+		} else if self.stress >= RELAX_STRESS {
+			loot::NORMAL
+		} else {
+			loot::CALM
+		}
+	}
+
+	pub fn record_hit(&mut self) {
+		self.stress += HIT_STRESS;
+		self.since_last_hit = 0;
+	}
+
+	pub fn record_kills(&mut self, kills: u32) {
+		self.stress += KILL_STRESS * kills as f64;
+		self.recent_kills += kills as f64;
+	}
+
+	/// Picks a random location just outside the page the camera is showing.
+	pub fn spawn_location(&self, rng: &mut StdRng, map: &map::Map) -> (units::Game, units::Game) {
+		let left = map.get_page_x() * 20;
+		let top = map.get_page_y() * 20;
+
+		// only consider the sides of the page that are still inside the level
+		let mut sides: Vec<(u32, u32)> = Vec::new();
+		if left >= MIN_SPAWN_TILE + OFF_CAMERA_DISTANCE {
+			sides.push((left - OFF_CAMERA_DISTANCE, rng.gen_range(top..top + 20)));
+		}
+		if left + 20 + OFF_CAMERA_DISTANCE <= MAX_SPAWN_TILE {
+			sides.push((left + 20 + OFF_CAMERA_DISTANCE, rng.gen_range(top..top + 20)));
+		}
+		if top >= MIN_SPAWN_TILE + OFF_CAMERA_DISTANCE {
+			sides.push((rng.gen_range(left..left + 20), top - OFF_CAMERA_DISTANCE));
+		}
+		if top + 20 + OFF_CAMERA_DISTANCE <= MAX_SPAWN_TILE {
+			sides.push((rng.gen_range(left..left + 20), top + 20 + OFF_CAMERA_DISTANCE));
+		}
+
+		let (x, y) = sides[rng.gen_range(0..sides.len())];
+		(Director::clamp_tile(x).to_game(), Director::clamp_tile(y).to_game())
+	}
+
+	/// Picks a random location anywhere on the map that is not on screen.
+	pub fn scatter_location(&self, rng: &mut StdRng, map: &map::Map) -> (units::Game, units::Game) {
+		loop {
+			let x = units::Tile(rng.gen_range(MIN_SPAWN_TILE..MAX_SPAWN_TILE)).to_game();
+			let y = units::Tile(rng.gen_range(MIN_SPAWN_TILE..MAX_SPAWN_TILE)).to_game();
+			if !map.on_screen(x, y) {
+				return (x, y);
+			}
+		}
+	}
+
+	fn set_phase(&mut self, phase: Phase) {
+		self.phase = phase;
+		self.phase_timer = 0;
+		self.spawn_timer = match phase {
+			Phase::Peak => 0,
+			_           => BUILD_UP_INTERVAL
+		};
+	}
+
+	fn pick_kind(&self, rng: &mut StdRng) -> u32 {
+		// peaks lean on the fast chasers, if the level has any
+		if self.phase == Phase::Peak && self.mix[1] > 0 && rng.gen_range(0u32..2u32) == 0 {
+			return 2;
+		}
+		let total: u32 = self.mix.iter().sum();
+		let mut roll = rng.gen_range(0..total);
+		for (i, weight) in self.mix.iter().enumerate() {
+			if roll < *weight {
+				return i as u32 + 1;
+			}
+			roll -= weight;
+		}
+		1
+	}
+
+	fn clamp_tile(tile: u32) -> units::Tile {
+		if tile < MIN_SPAWN_TILE {
+			units::Tile(MIN_SPAWN_TILE)
+		} else if tile > MAX_SPAWN_TILE {
+			units::Tile(MAX_SPAWN_TILE)
+		} else {
+			units::Tile(tile)
+		}
+	}
+}
@@ -1,7 +1,10 @@
 use game::collisions::Rectangle;
 use game::map;
 use game::sprite;
+use game::status;
 use game::graphics;
+use game::health;
+use game::noise;
 
 use game::units;
 
@@ -11,6 +14,13 @@ use game::common::Character;
 static SPRITE_NUM_FRAMES:  units::Frame  = 2;
 static SPRITE_FPS:         units::Fps    = 20;
 
+// Hit points
+static SLOW_MAX_HP:        u32 = 2;
+static CRAZY_MAX_HP:       u32 = 1;
+static RANDOM_MAX_HP:      u32 = 1;
+static CLOUD_MAX_HP:       u32 = 1;
+static INVULNERABLE_FRAMES: i32 = 10;
+
 // Slow Zombie
 static SLOW_WALK_FRAME:  units::Tile = units::Tile(4);
 
@@ -35,6 +45,8 @@ static CLOUD_WALK_FRAME:  units::Tile = units::Tile(6);
 
 static CLOUD_WALKING_ACCEL: units::Acceleration = units::Acceleration(0.00083007812);
 static CLOUD_MAX_VELOCITY: units::Velocity      = units::Velocity(0.05859375);
+// updates between each glob a cloud zombie spits
+static CLOUD_SPIT_COOLDOWN: i32 = 150;
 
 // y-offsets for different horizontal facings.
 static WEST_OFFSET: units::Tile = units::Tile(2);
@@ -42,7 +54,8 @@ static EAST_OFFSET: units::Tile = units::Tile(3);
 
 pub trait Zombie {
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map);
-	fn set_acceleration(&mut self, player_x: units::Game, player_y: units::Game);
+	fn set_acceleration(&mut self, scent: Option<(units::Game, units::Game)>);
+	fn investigate(&mut self, x: units::Game, y: units::Game);
 	fn draw(&self, display: &mut graphics::Graphics);
 	fn damage_rectangle(&self) -> Rectangle;
 	fn zombie_type(&self) -> i32;
@@ -51,8 +64,18 @@ pub trait Zombie {
 	fn get_y(&self) -> units::Game;
 	fn get_map_x(&self) -> units::Game;
 	fn get_map_y(&self) -> units::Game;
-	fn kill_zombie(&mut self);
+	fn push_out_of(&mut self, rectangle: &Rectangle);
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult;
+	fn on_death(&mut self, damage: &health::Damage);
+	fn get_health(&self) -> &health::Health;
+	fn get_effects_mut(&mut self) -> &mut status::StatusEffects;
 	fn is_killed(&mut self) -> bool;
+
+	/// Whether the zombie spits at a player it can see, starting its cooldown
+	/// when it does. Most zombies can't spit at all.
+	fn ready_to_spit(&mut self) -> bool {
+		false
+	}
 }
 
 pub struct SlowZombie {
@@ -69,8 +92,9 @@ pub struct RandomZombie {
 }
 
 pub struct CloudZombie {
-	character: Character,
-	chasing:   bool
+	character:  Character,
+	chasing:    bool,
+	spit_timer: i32
 }
 
 impl SlowZombie {
@@ -80,6 +104,7 @@ impl SlowZombie {
 		let mut new_slow_zombie = SlowZombie { 
 			character: common::Character::new(x, y)
 		};
+		new_slow_zombie.character.health = health::Health::new(SLOW_MAX_HP, INVULNERABLE_FRAMES);
 
 		for motion in sprite::MOTIONS.iter() {
 			for facing in sprite::FACINGS.iter() {
@@ -119,6 +144,7 @@ impl Zombie for SlowZombie {
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		// calculate current position
 		self.character.elapsed_time = elapsed_time;
+		self.character.health.update();
 
 		// update sprite
 		self.character.current_motion(); // update motion once at beginning of frame for consistency
@@ -138,21 +164,41 @@ impl Zombie for SlowZombie {
 		self.character.update_y(map, SLOW_WALKING_ACCEL, SLOW_MAX_VELOCITY);
 	}
 
-	fn set_acceleration(&mut self, player_x: units::Game, player_y: units::Game) {
+	fn set_acceleration(&mut self, scent: Option<(units::Game, units::Game)>) {
+		// head for any noise being investigated, otherwise follow the player's
+		// scent, and shamble about once the player has broken their trail
+		if !self.character.update_investigation() {
+			match scent {
+				Some((scent_x, scent_y)) => {
+					self.character.target_x = scent_x;
+					self.character.target_y = scent_y;
+				},
+				None => { self.character.set_new_target(); }
+			}
+		}
+		let (target_x, target_y) = (self.character.target_x, self.character.target_y);
+
 		self.character.accel_x = match self.character.map_center_x() {
-			ref center if center < &player_x => 1,
-			ref center if center > &player_x => -1,
+			ref center if center < &target_x => 1,
+			ref center if center > &target_x => -1,
 			_				            => 0
 		};
 		self.character.accel_y = match self.character.map_center_y() {
-			ref center if center < &player_y => 1, 
-			ref center if center > &player_y => -1, 
+			ref center if center < &target_y => 1, 
+			ref center if center > &target_y => -1, 
 			_				            => 0
 		};
 	}
 
+	fn investigate(&mut self, x: units::Game, y: units::Game) {
+		self.character.investigate(x, y, noise::INVESTIGATE_FRAMES);
+	}
+
 	fn draw(&self, display: &mut graphics::Graphics) {
 		self.character.draw(display);
+		if !self.character.is_killed() {
+			self.character.effects.draw(display, self.character.x, self.character.y);
+		}
 	}
 
 	fn damage_rectangle(&self) -> Rectangle {
@@ -183,10 +229,31 @@ impl Zombie for SlowZombie {
 		self.character.map_center_y()
 	}
 
-	fn kill_zombie(&mut self) {
+	fn push_out_of(&mut self, rectangle: &Rectangle) {
+		self.character.push_out_of(rectangle);
+	}
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		let result = self.character.take_damage(&damage);
+		if result == health::DamageResult::Killed {
+			self.on_death(&damage);
+		}
+		result
+	}
+
+	#[allow(unused_variables)]
+	fn on_death(&mut self, damage: &health::Damage) {
 		self.character.kill_character();
 	}
 
+	fn get_health(&self) -> &health::Health {
+		&self.character.health
+	}
+
+	fn get_effects_mut(&mut self) -> &mut status::StatusEffects {
+		&mut self.character.effects
+	}
+
 	fn is_killed(&mut self) -> bool {
 		match self.character.killed {
 			0 => { true },
@@ -203,6 +270,7 @@ impl CrazyZombie {
 			character: common::Character::new(x, y),
 			chasing:   false
 		};
+		new_crazy_zombie.character.health = health::Health::new(CRAZY_MAX_HP, INVULNERABLE_FRAMES);
 
 		for motion in sprite::MOTIONS.iter() {
 			for facing in sprite::FACINGS.iter() {
@@ -242,6 +310,7 @@ impl Zombie for CrazyZombie {
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		// calculate current position
 		self.character.elapsed_time = elapsed_time;
+		self.character.health.update();
 
 		// update sprite
 		self.character.current_motion(); // update motion once at beginning of frame for consistency
@@ -267,19 +336,21 @@ impl Zombie for CrazyZombie {
 		self.character.update_y(map, acceleration, CRAZY_MAX_VELOCITY);
 	}
 
-	fn set_acceleration(&mut self, player_x: units::Game, player_y: units::Game) {
-		// if close to player, start chasing
-		self.chasing = match self.character.distance( player_x, player_y ) {
-			d if d < 100.0 => true,
-			_              => false
+	fn set_acceleration(&mut self, scent: Option<(units::Game, units::Game)>) {
+		// if close to a fresh scent, start chasing
+		let chase = match scent {
+			Some((x, y)) if self.character.distance( x, y ) < 100.0 => Some((x, y)),
+			_ => None
 		};
+		self.chasing = chase.is_some();
 
 		// keep going to target unless it has been reached
-		if !self.chasing {
+		if let Some((scent_x, scent_y)) = chase {
+			self.character.investigate_timer = 0;
+			self.character.target_x = scent_x;
+			self.character.target_y = scent_y;
+		} else if !self.character.update_investigation() {
 			self.character.set_new_target();
-		} else {
-		    self.character.target_x = player_x;
-			self.character.target_y = player_y;
 		}
 
 		self.character.accel_x = match self.character.map_center_x() {
@@ -294,8 +365,15 @@ impl Zombie for CrazyZombie {
 		};
 	}
 
+	fn investigate(&mut self, x: units::Game, y: units::Game) {
+		self.character.investigate(x, y, noise::INVESTIGATE_FRAMES);
+	}
+
 	fn draw(&self, display: &mut graphics::Graphics) {
 		self.character.draw(display);
+		if !self.character.is_killed() {
+			self.character.effects.draw(display, self.character.x, self.character.y);
+		}
 	}
 
 	fn damage_rectangle(&self) -> Rectangle {
@@ -326,10 +404,31 @@ impl Zombie for CrazyZombie {
 		self.character.map_center_y()
 	}
 
-	fn kill_zombie(&mut self) {
+	fn push_out_of(&mut self, rectangle: &Rectangle) {
+		self.character.push_out_of(rectangle);
+	}
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		let result = self.character.take_damage(&damage);
+		if result == health::DamageResult::Killed {
+			self.on_death(&damage);
+		}
+		result
+	}
+
+	#[allow(unused_variables)]
+	fn on_death(&mut self, damage: &health::Damage) {
 		self.character.kill_character();
 	}
 
+	fn get_health(&self) -> &health::Health {
+		&self.character.health
+	}
+
+	fn get_effects_mut(&mut self) -> &mut status::StatusEffects {
+		&mut self.character.effects
+	}
+
 	fn is_killed(&mut self) -> bool {
 		match self.character.killed {
 			0 => { true },
@@ -345,6 +444,7 @@ impl RandomZombie {
 		let mut new_random_zombie = RandomZombie { 
 			character: common::Character::new(x, y)
 		};
+		new_random_zombie.character.health = health::Health::new(RANDOM_MAX_HP, INVULNERABLE_FRAMES);
 
 		for motion in sprite::MOTIONS.iter() {
 			for facing in sprite::FACINGS.iter() {
@@ -384,6 +484,7 @@ impl Zombie for RandomZombie {
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		// calculate current position
 		self.character.elapsed_time = elapsed_time;
+		self.character.health.update();
 
 		// update sprite
 		self.character.current_motion(); // update motion once at beginning of frame for consistency
@@ -404,8 +505,10 @@ impl Zombie for RandomZombie {
 	}
 
 	#[allow(unused_variables)]
-	fn set_acceleration(&mut self, player_x: units::Game, player_y: units::Game) {
-		self.character.set_new_random_target();
+	fn set_acceleration(&mut self, scent: Option<(units::Game, units::Game)>) {
+		if !self.character.update_investigation() {
+			self.character.set_new_random_target();
+		}
 
 		self.character.accel_x = match self.character.map_center_x() {
 			ref center if center < &self.character.target_x => 1,
@@ -419,8 +522,15 @@ impl Zombie for RandomZombie {
 		};
 	}
 
+	fn investigate(&mut self, x: units::Game, y: units::Game) {
+		self.character.investigate(x, y, noise::INVESTIGATE_FRAMES);
+	}
+
 	fn draw(&self, display: &mut graphics::Graphics) {
 		self.character.draw(display);
+		if !self.character.is_killed() {
+			self.character.effects.draw(display, self.character.x, self.character.y);
+		}
 	}
 
 	fn damage_rectangle(&self) -> Rectangle {
@@ -451,10 +561,31 @@ impl Zombie for RandomZombie {
 		self.character.map_center_y()
 	}
 
-	fn kill_zombie(&mut self) {
+	fn push_out_of(&mut self, rectangle: &Rectangle) {
+		self.character.push_out_of(rectangle);
+	}
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		let result = self.character.take_damage(&damage);
+		if result == health::DamageResult::Killed {
+			self.on_death(&damage);
+		}
+		result
+	}
+
+	#[allow(unused_variables)]
+	fn on_death(&mut self, damage: &health::Damage) {
 		self.character.kill_character();
 	}
 
+	fn get_health(&self) -> &health::Health {
+		&self.character.health
+	}
+
+	fn get_effects_mut(&mut self) -> &mut status::StatusEffects {
+		&mut self.character.effects
+	}
+
 	fn is_killed(&mut self) -> bool {
 		match self.character.killed {
 			0 => { true },
@@ -468,9 +599,11 @@ impl CloudZombie {
 	           x: units::Game, y: units::Game) -> CloudZombie {
 
 		let mut new_cloud_zombie = CloudZombie { 
-			character: common::Character::new(x, y), 
-			chasing:   false
+			character:  common::Character::new(x, y), 
+			chasing:    false,
+			spit_timer: CLOUD_SPIT_COOLDOWN
 		};
+		new_cloud_zombie.character.health = health::Health::new(CLOUD_MAX_HP, INVULNERABLE_FRAMES);
 
 		for motion in sprite::MOTIONS.iter() {
 			for facing in sprite::FACINGS.iter() {
@@ -510,6 +643,7 @@ impl Zombie for CloudZombie {
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		// calculate current position
 		self.character.elapsed_time = elapsed_time;
+		self.character.health.update();
 
 		// update sprite
 		self.character.current_motion(); // update motion once at beginning of frame for consistency
@@ -524,24 +658,30 @@ impl Zombie for CloudZombie {
 			self.character.killed_sprite.get_mut(0).unwrap().update(elapsed_time);
 		}
 
+		if self.spit_timer > 0 {
+			self.spit_timer -= 1;
+		}
+
 		// run physics sim
 		self.character.update_x(map, CLOUD_WALKING_ACCEL, CLOUD_MAX_VELOCITY);
 		self.character.update_y(map, CLOUD_WALKING_ACCEL, CLOUD_MAX_VELOCITY);
 	}
 
-	fn set_acceleration(&mut self, player_x: units::Game, player_y: units::Game) {
-		// if close to player, start chasing
-		self.chasing = match self.character.distance( player_x, player_y ) {
-			d if d < 50.0 => true,
-			_             => false
+	fn set_acceleration(&mut self, scent: Option<(units::Game, units::Game)>) {
+		// if close to a fresh scent, start chasing
+		let chase = match scent {
+			Some((x, y)) if self.character.distance( x, y ) < 50.0 => Some((x, y)),
+			_ => None
 		};
+		self.chasing = chase.is_some();
 
 		// keep going to target unless it has been reached
-		if !self.chasing {
+		if let Some((scent_x, scent_y)) = chase {
+			self.character.investigate_timer = 0;
+			self.character.target_x = scent_x;
+			self.character.target_y = scent_y;
+		} else if !self.character.update_investigation() {
 			self.character.set_new_target();
-		} else {
-			self.character.target_x = player_x;
-			self.character.target_y = player_y;
 		}
 
 		self.character.accel_x = match self.character.map_center_x() {
@@ -556,8 +696,15 @@ impl Zombie for CloudZombie {
 		};
 	}
 
+	fn investigate(&mut self, x: units::Game, y: units::Game) {
+		self.character.investigate(x, y, noise::INVESTIGATE_FRAMES);
+	}
+
 	fn draw(&self, display: &mut graphics::Graphics) {
 		self.character.draw(display);
+		if !self.character.is_killed() {
+			self.character.effects.draw(display, self.character.x, self.character.y);
+		}
 	}
 
 	fn damage_rectangle(&self) -> Rectangle {
@@ -588,14 +735,43 @@ impl Zombie for CloudZombie {
 		self.character.map_center_y()
 	}
 
-	fn kill_zombie(&mut self) {
+	fn push_out_of(&mut self, rectangle: &Rectangle) {
+		self.character.push_out_of(rectangle);
+	}
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		let result = self.character.take_damage(&damage);
+		if result == health::DamageResult::Killed {
+			self.on_death(&damage);
+		}
+		result
+	}
+
+	#[allow(unused_variables)]
+	fn on_death(&mut self, damage: &health::Damage) {
 		self.character.kill_character();
 	}
 
+	fn get_health(&self) -> &health::Health {
+		&self.character.health
+	}
+
+	fn get_effects_mut(&mut self) -> &mut status::StatusEffects {
+		&mut self.character.effects
+	}
+
 	fn is_killed(&mut self) -> bool {
 		match self.character.killed {
 			0 => { true },
 			_ => { self.character.killed = self.character.killed - 1; false }
 		}
 	}
+
+	fn ready_to_spit(&mut self) -> bool {
+		if self.spit_timer > 0 || self.character.effects.is_immobile() {
+			return false;
+		}
+		self.spit_timer = CLOUD_SPIT_COOLDOWN;
+		true
+	}
 }
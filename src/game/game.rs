@@ -6,6 +6,8 @@ use std::io::Read;
 use std::path::Path;
 use std::vec::Vec;
 use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use std::string::String;
 use std::time::Instant;
 
@@ -16,20 +18,33 @@ use sdl2::keyboard::Keycode;
 
 use rand;
 
-pub use game::units::{AsGame};
+pub use game::units::{AsGame,AsTile};
 
 pub use game::units;
+pub use game::campaign;
+pub use game::common;
+pub use game::compass;
+pub use game::daily;
+pub use game::director;
 pub use game::enemies;
 pub use game::powerups;
+pub use game::projectile;
 pub use game::traps;
+pub use game::weapons;
 pub use game::map;
+pub use game::mode;
 pub use game::input;
+pub use game::inventory;
+pub use game::leaderboard;
+pub use game::loot;
 pub use game::vehicle;
-pub use game::car;
-pub use game::heli;
 pub use game::player;
 pub use game::graphics;
+pub use game::health;
 pub use game::music;
+pub use game::noise;
+pub use game::status;
+pub use game::survival;
 pub use game::collisions::Rectangle;
 
 const TARGET_FRAMERATE: units::Fps  =  60;
@@ -42,22 +57,109 @@ pub static SCREEN_HEIGHT: units::Tile = units::Tile(20);
 
 pub static POSSIBLE_CHARACTER_TILES: u32 = 58;
 pub static MAX_ENEMIES:              u32 = 30;
-pub static MAX_POWERUPS:             u32 = 20;
-// pub static MAX_TRAPS:                uint = 5;
+pub static POWERUPS_PER_LEVEL:       (u32, u32) = (6, 20);
+pub static INVENTORY_SLOTS:          usize = 3;
+pub static INVENTORY_HUD_X:          u32 = 200;
+pub static WEAPON_HUD_X:             u32 = 340;
+pub static PARTS_HUD_X:              u32 = 470;
+pub static TRAP_HUD_Y:               u32 = 36;
+pub static MAX_TRAPS:                u32 = 6;
+// what the level generator scatters around, barricades are only ever placed
+pub static LEVEL_TRAP_KINDS:         [i32; 6] = [traps::BEAR_TRAP, traps::SPIKES, traps::LANDMINE, traps::TRIPWIRE, traps::PIT, traps::SPRING];
+pub static TRAP_KINDS:               usize = 3;
+pub static MAX_PLACED_TRAPS:         u32 = 5;
 
 pub static POSSIBLE_PART_RANGE: (u32, u32) = (20, 55);
-pub static LEVEL_PARTS:                 u32 = 3;
+pub static MAX_CARRIED_PARTS:           usize = 3;
+pub static PART_DROP_FRAMES:            i32 = 90;
 pub static LEVEL_1_TIME:                 i32 = 5000;
-pub static LEVEL_1_CINEMATIC_FRAMES:     i32 = 300;
 pub static LEVEL_2_TIME:                 i32 = 2000;
-pub static LEVEL_2_CINEMATIC_FRAMES:     i32 = 300;
+
+// escaping in the finished vehicle
+pub static CRASH_PENALTY:       i32 = 500;
+pub static RAM_DAMAGE:          u32 = 10;
+
+// zombies go for the vehicle once the player strays this far from it, as
+// long as they are close enough to it themselves
+pub static VEHICLE_GUARD_RADIUS: units::Tile = units::Tile(10);
+pub static VEHICLE_AGGRO_RADIUS: units::Tile = units::Tile(12);
+pub static VEHICLE_DAMAGE:       u32 = 1;
+// how far from the vehicle a knocked loose part lands
+pub static LOOSE_PART_SPREAD:    u32 = 4;
 
 pub static PLAYER_STARTING_X: units::Tile = units::Tile(1);
 pub static PLAYER_STARTING_Y: units::Tile = units::Tile(4);
+pub static PARTNER_STARTING_X: units::Tile = units::Tile(1);
+pub static PARTNER_STARTING_Y: units::Tile = units::Tile(6);
+
+// co-op
+pub static REVIVE_HEALTH:        u32 = 1;
+pub static PARTNER_HUD_Y:        u32 = 52;
+pub static PARTNER_MARKER_SIZE:  u32 = 8;
+pub static PARTNER_MARKER_COLOR: (u8, u8, u8) = (40, 200, 240);
+// how far over the edge of the page a player can step, and how close their
+// partner has to be to that edge to let them
+pub static PAGE_SLACK:           units::HalfTile = units::HalfTile(1);
+pub static CROSSING_RANGE:       units::Tile = units::Tile(2);
 
 pub static VEHICLE_STARTING_X: units::Tile = units::Tile(1);
 pub static VEHICLE_STARTING_Y: units::Tile = units::Tile(1);
 
+// damage dealt by the player's attacks and the world
+pub static BULLET_DAMAGE:       u32 = 3;
+pub static EXPLOSION_DAMAGE:    u32 = 10;
+pub static TRAP_DAMAGE:         u32 = 3;
+pub static SPIKE_DAMAGE:        u32 = 2;
+pub static LANDMINE_DAMAGE:     u32 = 2;
+pub static LANDMINE_RADIUS:     units::Tile = units::Tile(3);
+pub static CONTACT_DAMAGE:      u32 = 1;
+pub static BAT_KNOCKBACK:       units::Velocity = units::Velocity(0.2);
+pub static CONTACT_KNOCKBACK:   units::Velocity = units::Velocity(0.15);
+
+// healing
+pub static HEART_HEAL:          u32 = 1;
+pub static HEART_CONTAINER_HP:  u32 = 1;
+// level bonus for finishing at full health, whatever the max
+pub static HEALTH_SCORE:        i32 = 3000;
+
+// how long status effects from powerups and attacks last (in updates)
+pub static FREEZE_FRAMES:       i32 = 300;
+pub static STUN_FRAMES:         i32 = 30;
+pub static BURN_FRAMES:         i32 = 180;
+pub static SLOW_FRAMES:         i32 = 120;
+pub static ENRAGE_FRAMES:       i32 = 600;
+pub static BURN_DAMAGE:         u32 = 1;
+
+// cursed powerups are rerolled every so often, flashing a warning beforehand
+pub static DEBUFF_ROLL_INTERVAL:  i32 = 300;
+pub static DEBUFF_WARNING_FRAMES: i32 = 60;
+pub static DEBUFF_KINDS:          u32 = 6;
+pub static REVERSE_FRAMES:        i32 = 300;
+pub static DARKNESS_FRAMES:       i32 = 360;
+pub static DARKNESS_RADIUS:       u32 = 96;
+pub static DEBUFF_SLOW_FRAMES:    i32 = 300;
+pub static MAGNET_FRAMES:         i32 = 240;
+pub static MAGNET_PULL_INTERVAL:  i32 = 30;
+pub static HORDE_SIZE:            u32 = 6;
+
+// projectiles
+pub static BULLET_SPEED:        units::Velocity = units::Velocity(0.6);
+pub static BULLET_LIFETIME:     i32 = 90;
+pub static SPIT_SPEED:          units::Velocity = units::Velocity(0.3);
+pub static SPIT_LIFETIME:       i32 = 60;
+pub static SPIT_DAMAGE:         u32 = 1;
+pub static SPIT_RANGE:          units::Tile = units::Tile(5);
+
+// how often heavy footsteps are heard while sprinting (in updates)
+pub static SPRINT_NOISE_INTERVAL: i32 = 30;
+
+// zombie drops
+pub static ZOMBIE_TYPES:        i32 = 4;
+pub static AMMO_DROP:           u32 = 6;
+pub static TIME_BONUS:          i32 = 300;
+pub static MAGNET_RADIUS:       units::Tile = units::Tile(2);
+pub static MAGNET_SPEED:        units::Game = units::Game(2.0);
+
 // hadle the annoying Rect i32
 macro_rules! rect(
     ($x:expr, $y:expr, $w:expr, $h:expr) => (
@@ -68,6 +170,7 @@ macro_rules! rect(
 /// An instance of the `just-run` game with its own event loop.
 pub struct Game<'engine> {
 	player:     player::Player,
+	partner:    Option<player::Player>,
 	enemies:    Vec<Box<dyn enemies::Zombie>>,
 	powerups:   Vec<Box<dyn powerups::Powerup>>,
 	traps:      Vec<Box<dyn traps::Trap>>,
@@ -75,22 +178,47 @@ pub struct Game<'engine> {
 	activated:  Vec<Box<dyn powerups::Powerup>>,
 	tripped:    Vec<Box<dyn traps::Trap>>,
 	parts:      Vec<Box<dyn vehicle::Part>>,
-	coll_parts: Vec<Box<dyn vehicle::Part>>,
+	noises:     Vec<noise::Noise>,
+	projectiles: Vec<projectile::Projectile>,
+	inventory:  inventory::Inventory,
+	loot_tables: Vec<loot::LootTable>,
+	powerup_loot: loot::LootTable,
+	zombie_loot: Vec<loot::LootTable>,
+	debuff_odds: Vec<(i32, u32)>,
+	trap_kits:  Vec<u32>,
 	vehicle:    Box<dyn vehicle::Vehicle>,
+	vehicle_definitions: Vec<vehicle::VehicleDefinition>,
+	compass:    compass::Compass,
 	map:        map::Map,
+	director:   director::Director,
+	mode:       mode::Mode,
+	campaign:   Vec<campaign::CampaignLevel>,
+	campaign_unlocked: usize,
+	campaign_selected: usize,
+	survival:   survival::Survival,
+	survival_leaderboard: leaderboard::Leaderboard,
+	daily:      daily::Challenge,
+	daily_results: daily::DailyResults,
+	daily_scored: bool,
+	level_rng:  StdRng,
 
 	display:        graphics::Graphics,
 	music:			music::Music<'engine>,
 	context:        &'engine sdl2::Sdl,
 	controller:     input::Input,
 	paused:         bool,
+	in_menu:        bool,
+	coop:           bool,
 	updates:        i32,
 	level:          i32, 
 	highscore:      i32,
 	score:          i32,
+	kills:          u32,
 	timer:          i32,
 	completed_lvl:  bool,
-	freeze_counter: i32, 
+	part_pickup_timer: i32,
+	selected_trap:  usize,
+	traps_placed:   u32,
 	alt_control:    bool
 }
 
@@ -102,7 +230,7 @@ impl<'e> Game<'e> {
 		let mut display = graphics::Graphics::new(context);
 		let music = music::Music::new(context);
 		let controller  = input::Input::new();
-		let mut rng = rand::thread_rng();
+		let seed: u64 = rand::thread_rng().gen();
 		let enemies_vector: Vec<Box<dyn enemies::Zombie>> = Vec::new();
 		let powerups_vector: Vec<Box<dyn powerups::Powerup>> = Vec::new();
 		let traps_vector: Vec<Box<dyn traps::Trap>> = Vec::new();
@@ -110,15 +238,37 @@ impl<'e> Game<'e> {
 		let activated_vector: Vec<Box<dyn powerups::Powerup>> = Vec::new();
 		let tripped_vector: Vec<Box<dyn traps::Trap>> = Vec::new();
 		let part_vector: Vec<Box<dyn vehicle::Part>> = Vec::new();
-		let coll_part_vector: Vec<Box<dyn vehicle::Part>> = Vec::new();
+		let vehicle_definitions = vehicle::load_vehicles(vehicle::VEHICLE_DATA);
+		let loot_tables = loot::load_loot(loot::LOOT_DATA);
+		let campaign = campaign::load_campaign(campaign::CAMPAIGN_DATA, &vehicle_definitions, &loot_tables);
+		let campaign_unlocked = cmp::min(campaign::load_progress(), campaign.len());
+		let vehicle = vehicle::DataVehicle::new(
+			&mut display,
+			&vehicle_definitions,
+			0,
+			VEHICLE_STARTING_X.to_game(),
+			VEHICLE_STARTING_Y.to_game()
+		);
 
 		let mut game = Game {
-			map: map::Map::load_map(&mut display, 1 as i32),
+			map: map::Map::load_map(&mut display, &map::Layout::Open, seed),
+			director: director::Director::new(),
+			mode: mode::Mode::Escape,
+			campaign,
+			campaign_unlocked,
+			campaign_selected: campaign_unlocked,
+			survival: survival::Survival::new(),
+			survival_leaderboard: leaderboard::Leaderboard::load(survival::LEADERBOARD_FILE),
+			daily: daily::Challenge::today(),
+			daily_results: daily::DailyResults::load(),
+			daily_scored: false,
+			level_rng: StdRng::seed_from_u64(seed),
 			player: player::Player::new(
 				&mut display,
 				PLAYER_STARTING_X.to_game(),
 				PLAYER_STARTING_Y.to_game()
 			),
+			partner: None,
 
 			enemies: enemies_vector,
 			powerups: powerups_vector,
@@ -127,41 +277,49 @@ impl<'e> Game<'e> {
 			activated: activated_vector,
 			tripped: tripped_vector,
 			parts: part_vector,
-			coll_parts: coll_part_vector,
-
-			vehicle: Box::new( heli::Helicopter::new(
-				&mut display, 
-				VEHICLE_STARTING_X.to_game(), 
-				VEHICLE_STARTING_Y.to_game()
-			) ) as Box<dyn vehicle::Vehicle>,
+			noises: Vec::new(),
+			projectiles: Vec::new(),
+			inventory: inventory::Inventory::new(INVENTORY_SLOTS),
+			powerup_loot: loot::LootTable::powerups(&loot_tables),
+			zombie_loot: (1..ZOMBIE_TYPES + 1).map(|zombie_type| loot::LootTable::zombie_drops(&loot_tables, zombie_type)).collect(),
+			loot_tables,
+			debuff_odds: powerups::load_debuff_odds(powerups::POWERUP_DATA),
+			trap_kits: vec![0; TRAP_KINDS],
+
+			vehicle: Box::new(vehicle) as Box<dyn vehicle::Vehicle>,
+			vehicle_definitions,
+			compass: compass::Compass::new(),
 
 			display:        display,
 			music:			music,
 			context:        context,
 			controller:     controller, 
 			paused:         true,
+			in_menu:        true,
+			coop:           false,
 			updates:        0,
 			level:          1,
 			highscore:      Game::get_highscore(),
 			score:          0,
+			kills:          0,
 			timer:          LEVEL_1_TIME,
 			completed_lvl:  false,
-			freeze_counter: 0, 
+			part_pickup_timer: 0,
+			selected_trap:  0,
+			traps_placed:   0,
 			alt_control:    false
 		};
-		let number_of_zombies = rng.gen_range(20u32..MAX_ENEMIES);
-		for _ in 0.. number_of_zombies {
-		  	game.spawn_zombie(rng.gen_range(1u32..5u32), (units::Game(0.0), units::Game(0.0)));
-		}
-		let number_of_powerups = rng.gen_range(0u32..MAX_POWERUPS);
-		for _ in 0.. number_of_powerups {
-			game.spawn_powerup(rng.gen_range(1u32..7u32));
-		}
-		// let number_of_traps = rng.gen_range(0u, MAX_TRAPS);
-		// for _ in range(0, number_of_traps) {
-		// 	game.spawn_trap(1);
-		// }
-		for i in 0.. LEVEL_PARTS {
+		for kind in game.director.start_level(&mut game.level_rng, 1, director::DEFAULT_MIX) {
+			let location = game.director.scatter_location(&mut game.level_rng, &game.map);
+			game.spawn_zombie(kind, location);
+		}
+		game.spawn_level_powerups(loot::CALM);
+		let number_of_traps = game.level_rng.gen_range(0u32..MAX_TRAPS);
+		for _ in 0.. number_of_traps {
+			let kind = LEVEL_TRAP_KINDS[game.level_rng.gen_range(0..LEVEL_TRAP_KINDS.len())];
+			game.spawn_trap(kind);
+		}
+		for i in 0.. game.vehicle.part_count() {
 			game.spawn_part(i);
 		}
 
@@ -169,43 +327,26 @@ impl<'e> Game<'e> {
 	}
 
 	pub fn spawn_zombie(&mut self, kind: u32, location: (units::Game, units::Game)) {
-		let mut rng = rand::thread_rng();
+		let rng = &mut self.level_rng;
+		let (x, y) = match location {
+			(units::Game(0.0), units::Game(0.0)) => (
+				(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
+				(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+			),
+			(x, y) => (x, y)
+		};
 		let zombie = match kind {
-			1 => {
-				Box::new( enemies::SlowZombie::new(
-					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
-				) ) as Box<dyn enemies::Zombie>
-			}
-			2 => {
-				Box::new( enemies::CrazyZombie::new(
-					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
-				) ) as Box<dyn enemies::Zombie>
-			}
-			3 => {
-				Box::new( enemies::RandomZombie::new(
-					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
-				) ) as Box<dyn enemies::Zombie>
-			}
-			_ => {
-				match location {
-					(units::Game(0.0), units::Game(0.0)) => Box::new( enemies::CloudZombie::new(
-																&mut self.display, 
-																(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-																(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
-															) ) as Box<dyn enemies::Zombie>,
-					(x, y) => Box::new( enemies::CloudZombie::new(&mut self.display, x, y) ) as Box<dyn enemies::Zombie>,
-				}
-			}
+			1 => Box::new( enemies::SlowZombie::new(&mut self.display, x, y) ) as Box<dyn enemies::Zombie>,
+			2 => Box::new( enemies::CrazyZombie::new(&mut self.display, x, y) ) as Box<dyn enemies::Zombie>,
+			3 => Box::new( enemies::RandomZombie::new(&mut self.display, x, y) ) as Box<dyn enemies::Zombie>,
+			_ => Box::new( enemies::CloudZombie::new(&mut self.display, x, y) ) as Box<dyn enemies::Zombie>
 		};
 		let colliding_tiles = self.map.get_colliding_tiles(&zombie.damage_rectangle());
-		let (old_x, old_y) = location;
-		let new_location = (old_x + units::Game(1.0), old_y + units::Game(1.0));
+		// random spawns pick a fresh spot, requested spawns shuffle out of the wall
+		let new_location = match location {
+			(units::Game(0.0), units::Game(0.0)) => location,
+			(old_x, old_y) => (old_x + units::Game(1.0), old_y + units::Game(1.0))
+		};
 		for tile in colliding_tiles.iter() {
 			if tile.tile_type == map::TileType::Wall {
 				self.spawn_zombie(kind, new_location);
@@ -216,52 +357,136 @@ impl<'e> Game<'e> {
 		self.enemies.push(zombie);
 	}
 
-	pub fn spawn_powerup(&mut self, kind: u32) {
-		let mut rng = rand::thread_rng();
-		let powerup = match kind {
+	/// Scatters the level's powerups, picked from the powerup loot table
+	/// with the weights for `difficulty`.
+	pub fn spawn_level_powerups(&mut self, difficulty: usize) {
+		let (min, max) = POWERUPS_PER_LEVEL;
+		let count = self.level_rng.gen_range(min..max);
+		for kind in self.powerup_loot.fill(&mut self.level_rng, count, self.level, difficulty) {
+			self.spawn_powerup(kind);
+		}
+	}
+
+	pub fn create_powerup(&mut self, kind: u32, x: units::Game, y: units::Game) -> Box<dyn powerups::Powerup> {
+		match kind {
 			1 => {
 				Box::new( powerups::CricketBat::new(
 					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+					x,
+					y
 				) ) as Box<dyn powerups::Powerup>
 			}
 			2 => {
 				Box::new( powerups::KillZombie::new(
 					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+					x,
+					y
 				) ) as Box<dyn powerups::Powerup>
 			}
 			3 => {
 				Box::new( powerups::WipeOut::new(
 					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+					x,
+					y
 				) ) as Box<dyn powerups::Powerup>
 			}
 			4 => {
 				Box::new( powerups::Freeze::new(
 					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+					x,
+					y
 				) ) as Box<dyn powerups::Powerup>
 			}
 			5 => {
 				Box::new( powerups::Teleport::new(
 					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+					x,
+					y
 				) ) as Box<dyn powerups::Powerup>
 			} 
+			7 => {
+				Box::new( powerups::EnergyDrink::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
+			8 => {
+				Box::new( powerups::Adrenaline::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
+			9 => {
+				Box::new( powerups::WeaponPickup::new(
+					&mut self.display, 
+					x,
+					y,
+					weapons::PISTOL
+				) ) as Box<dyn powerups::Powerup>
+			}
+			10 => {
+				Box::new( powerups::WeaponPickup::new(
+					&mut self.display, 
+					x,
+					y,
+					weapons::MACHINE_GUN
+				) ) as Box<dyn powerups::Powerup>
+			}
+			11 => {
+				Box::new( powerups::Heart::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
+			12 => {
+				Box::new( powerups::HeartContainer::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
+			13 => {
+				Box::new( powerups::TrapKit::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
+			14 => {
+				Box::new( powerups::Ammo::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
+			15 => {
+				Box::new( powerups::TimeBonus::new(
+					&mut self.display, 
+					x,
+					y
+				) ) as Box<dyn powerups::Powerup>
+			}
 			_ => {
 				Box::new( powerups::Nuke::new(
 					&mut self.display, 
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game(),
-					(units::Tile(rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES))).to_game()
+					x,
+					y
 				) ) as Box<dyn powerups::Powerup>
 			}
-		};
+		}
+	}
+
+	pub fn spawn_powerup(&mut self, kind: u32) {
+		let rng = &mut self.level_rng;
+		let (x, y) = (rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES), rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES));
+		let powerup = self.create_powerup(
+			kind,
+			units::Tile(x).to_game(),
+			units::Tile(y).to_game()
+		);
 		let colliding_tiles = self.map.get_colliding_tiles(&powerup.damage_rectangle());
 		for tile in colliding_tiles.iter() {
 			if tile.tile_type == map::TileType::Wall {
@@ -273,22 +498,42 @@ impl<'e> Game<'e> {
 		self.powerups.push(powerup);
 	}
 
-	// pub fn spawn_trap(&mut self, kind: uint) {
-	// 	let mut rng = task_rng();
-	// 	match kind {
-	// 		_ => {
-	// 			let trap = box traps::BearTrap::new(
-	// 							&mut self.display, 
-	// 							(units::Tile(rng.gen_range(1u, POSSIBLE_CHARACTER_TILES))).to_game(),
-	// 							(units::Tile(rng.gen_range(1u, POSSIBLE_CHARACTER_TILES))).to_game()
-	// 						);
-	// 			self.traps.push(trap as Box<traps::Trap>);
-	// 		}
-	// 	};
-	// }
+	pub fn spawn_trap(&mut self, kind: i32) {
+		let x = units::Tile(self.level_rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES));
+		let y = units::Tile(self.level_rng.gen_range(1u32..POSSIBLE_CHARACTER_TILES));
+		if !self.spawn_trap_at(kind, x, y) {
+			self.spawn_trap(kind);
+		}
+	}
+
+	/// Sets a trap on the given tile, returns false if a wall is in the way.
+	pub fn spawn_trap_at(&mut self, kind: i32, x: units::Tile, y: units::Tile) -> bool {
+		let trap = self.create_trap(kind, x.to_game(), y.to_game());
+		let colliding_tiles = self.map.get_colliding_tiles(&trap.damage_rectangle());
+		for tile in colliding_tiles.iter() {
+			if tile.tile_type == map::TileType::Wall {
+				return false;
+			}
+		}
+
+		self.traps.push(trap);
+		true
+	}
+
+	pub fn create_trap(&mut self, kind: i32, x: units::Game, y: units::Game) -> Box<dyn traps::Trap> {
+		match kind {
+			traps::BEAR_TRAP => Box::new( traps::BearTrap::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>,
+			traps::SPIKES    => Box::new( traps::Spikes::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>,
+			traps::LANDMINE  => Box::new( traps::Landmine::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>,
+			traps::TRIPWIRE  => Box::new( traps::Tripwire::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>,
+			traps::PIT       => Box::new( traps::Pit::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>,
+			traps::SPRING    => Box::new( traps::Spring::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>,
+			_                => Box::new( traps::Barricade::new(&mut self.display, x, y) ) as Box<dyn traps::Trap>
+		}
+	}
 
 	pub fn spawn_part(&mut self, kind: u32) {
-		let mut rng = rand::thread_rng();
+		let rng = &mut self.level_rng;
 		let (min, max) = POSSIBLE_PART_RANGE;
 		let mut x = rng.gen_range(0..max);
 		let mut y = rng.gen_range(0..max);
@@ -297,58 +542,12 @@ impl<'e> Game<'e> {
 		} else if y < 20 {
 			x = rng.gen_range(min..max);
 		}
-		let part = match self.vehicle.get_type() {
-			1 => {
-				match kind {
-					0 => {
-						Box::new( heli::Prop::new(
-							&mut self.display, 
-							units::Tile(x).to_game(),
-							units::Tile(y).to_game()
-						) ) as Box<dyn vehicle::Part>
-					},
-					1 => {
-						Box::new( heli::Windshield::new(
-							&mut self.display, 
-							units::Tile(x).to_game(),
-							units::Tile(y).to_game()
-						) ) as Box<dyn vehicle::Part>
-					},
-					_ => {
-						Box::new( heli::Bar::new(
-							&mut self.display, 
-							units::Tile(x).to_game(),
-							units::Tile(y).to_game()
-						) ) as Box<dyn vehicle::Part>
-					}
-				}
-			},
-			_ => {
-				match kind {
-					0 => {
-						Box::new( car::Tire::new(
-							&mut self.display, 
-							units::Tile(x).to_game(),
-							units::Tile(y).to_game()
-						) ) as Box<dyn vehicle::Part>
-					},
-					1 => {
-						Box::new( car::Door::new(
-							&mut self.display, 
-							units::Tile(x).to_game(),
-							units::Tile(y).to_game()
-						) ) as Box<dyn vehicle::Part>
-					},
-					_ => {
-						Box::new( car::Engine::new(
-							&mut self.display, 
-							units::Tile(x).to_game(),
-							units::Tile(y).to_game()
-						) ) as Box<dyn vehicle::Part>
-					}
-				}
-			}
-		};
+		let part = self.vehicle.make_part(
+			&mut self.display,
+			kind,
+			units::Tile(x).to_game(),
+			units::Tile(y).to_game()
+		);
 		let colliding_tiles = self.map.get_colliding_tiles(&part.damage_rectangle());
 		for tile in colliding_tiles.iter() {
 			if tile.tile_type == map::TileType::Wall {
@@ -370,28 +569,143 @@ impl<'e> Game<'e> {
 		self.map.draw_background(&mut self.display);
 		self.display.switch_buffers();
 		self.display.draw_text("JUST F&#%IN RUN!!!", rect!(45, 50, 550, 200));
-		let score_string = String::from("CURRENT HIGHSCORE: ") + &self.highscore.to_string();
+		let highscore = match self.mode {
+			mode::Mode::Survival => self.survival_leaderboard.best(),
+			mode::Mode::Daily    => self.daily_results.best(),
+			_                    => self.highscore
+		};
+		let score_string = String::from("CURRENT HIGHSCORE: ") + &highscore.to_string();
 		self.display.draw_text(&score_string, rect!(120, 300, 400, 100));
+		let mode_string = String::from("MODE: ") + self.mode.name() + " (PRESS M TO CHANGE)";
+		self.display.draw_text(&mode_string, rect!(160, 420, 300, 40));
+		if self.mode == mode::Mode::Campaign {
+			let level = &self.campaign[self.campaign_selected - 1];
+			let level_string = String::from("LEVEL ") + &self.campaign_selected.to_string() + "/" + &self.campaign.len().to_string()
+				+ ": " + &level.name.to_uppercase() + " (LEFT/RIGHT TO PICK)";
+			self.display.draw_text(&level_string, rect!(160, 460, 300, 30));
+		}
+		if self.mode == mode::Mode::Daily {
+			let daily_string = self.daily.date.clone() + ": " + &self.daily.modifiers.describe();
+			self.display.draw_text(&daily_string, rect!(160, 460, 8 * daily_string.len(), 20));
+			if let Some(entry) = self.daily_results.get(&self.daily.date) {
+				let today_string = String::from("TODAY'S SCORE: ") + &entry.score.to_string() + " (PRACTICE ONLY)";
+				self.display.draw_text(&today_string, rect!(160, 480, 8 * today_string.len(), 20));
+			}
+		}
 		self.display.draw_text("PRESS ENTER AND START RUNNING...", rect!(160, 500, 300, 50));
+		let coop_string = String::from("CO-OP: ") + if self.coop { "ON" } else { "OFF" } + " (PRESS P TO CHANGE)";
+		self.display.draw_text(&coop_string, rect!(160, 560, 300, 30));
 		self.display.switch_buffers();
 	}
 
 	pub fn draw_status_bar(&mut self) {
-		let score_string = String::from("TIMER: ") + &self.timer.to_string();
-		self.display.draw_text(&score_string, rect!(500, 0, 100, 30));
-		self.display.draw_health(self.player.get_health());
+		if self.mode.is_timed() {
+			let score_string = String::from("TIMER: ") + &self.timer.to_string();
+			self.display.draw_text(&score_string, rect!(500, 0, 100, 30));
+		} else {
+			let time_string = String::from("TIME: ") + &self.survival.seconds().to_string();
+			self.display.draw_text(&time_string, rect!(500, 0, 100, 30));
+			let kills_string = String::from("KILLS: ") + &self.survival.get_kills().to_string();
+			self.display.draw_text(&kills_string, rect!(500, 30, 100, 30));
+		}
+		self.display.draw_health(self.player.get_health(), self.player.get_max_health());
+		let stamina_color = if self.player.is_exhausted() {
+			(200, 40, 40)
+		} else if self.player.is_stamina_boosted() {
+			(240, 200, 40)
+		} else {
+			(40, 200, 40)
+		};
+		self.display.draw_bar(self.player.get_stamina(), self.player.get_max_stamina(), rect!(2, 27, 71, 6), stamina_color);
+		if self.player.is_hidden() {
+			self.display.draw_text("HIDDEN", rect!(76, 24, 48, 12));
+		}
+		if let Some(ref partner) = self.partner {
+			self.display.draw_health_at(partner.get_health(), partner.get_max_health(), 0, PARTNER_HUD_Y);
+			let stamina_color = if partner.is_exhausted() {
+				(200, 40, 40)
+			} else if partner.is_stamina_boosted() {
+				(240, 200, 40)
+			} else {
+				PARTNER_MARKER_COLOR
+			};
+			self.display.draw_bar(partner.get_stamina(), partner.get_max_stamina(), rect!(2, PARTNER_HUD_Y + 27, 71, 6), stamina_color);
+			if partner.is_hidden() {
+				self.display.draw_text("HIDDEN", rect!(76, PARTNER_HUD_Y + 24, 48, 12));
+			}
+		}
+		self.inventory.draw(&mut self.display, INVENTORY_HUD_X);
+		for (i, part) in self.player.get_parts().iter().enumerate() {
+			part.draw_icon(&mut self.display, units::Game(PARTS_HUD_X as f64 + (i as f64 * 45.0)), units::Game(32.0));
+		}
+		if let Some(ref partner) = self.partner {
+			for (i, part) in partner.get_parts().iter().enumerate() {
+				part.draw_icon(&mut self.display, units::Game(PARTS_HUD_X as f64 + (i as f64 * 45.0)), units::Game((PARTNER_HUD_Y + 32) as f64));
+			}
+		}
+		if let Some(weapon) = self.player.get_weapon() {
+			weapon.draw_icon(&mut self.display, units::Game(WEAPON_HUD_X as f64), units::Game(4.0));
+			let ammo_string = weapon.get_ammo().to_string();
+			self.display.draw_text(&ammo_string, rect!(WEAPON_HUD_X + 20, 2, 10 * ammo_string.len(), 20));
+		}
+		let kits = self.trap_kits[self.selected_trap];
+		if kits > 0 {
+			let trap_string = String::from(Game::trap_name(self.selected_trap as i32 + 1)) + " x" + &kits.to_string()
+				+ " (" + &self.traps_placed.to_string() + "/" + &MAX_PLACED_TRAPS.to_string() + ")";
+			self.display.draw_text(&trap_string, rect!(2, TRAP_HUD_Y, 8 * trap_string.len(), 14));
+		}
+	}
+
+	/// Ends the run: records how it went, shows the game over screen and
+	/// starts a new game.
+	fn game_over(&mut self) {
+		let place = if self.mode == mode::Mode::Survival {
+			let entry = self.survival.entry();
+			self.survival_leaderboard.submit(entry)
+		} else {
+			None
+		};
+		self.finish_daily_attempt();
+		self.draw_game_over_screen(place);
+		self.restart();
 	}
 
-	pub fn draw_game_over_screen(&mut self) {
+	/// `place` is where a survival run landed on the leaderboard, if it did.
+	pub fn draw_game_over_screen(&mut self, place: Option<usize>) {
 		self.display.draw_text("GAME OVER MAN!", rect!(45, 100, 550, 200));
+		if self.mode == mode::Mode::Survival {
+			self.draw_survival_results(place);
+			return;
+		}
 		let score_string = String::from("YOUR SCORE: ") + &self.score.to_string();
 		self.display.draw_text(&score_string, rect!(120, 300, 400, 100));
 		self.display.draw_text("PRESS ENTER TO RUN SOME MORE...", rect!(160, 500, 300, 50));
 		self.display.switch_buffers();
 	}
 
+	/// Shows the briefing for the campaign level about to start.
+	pub fn draw_intro_screen(&mut self) {
+		let (name, intro) = {
+			let level = &self.campaign[self.level as usize - 1];
+			(level.name.to_uppercase(), level.intro.clone())
+		};
+		self.display.clear_buffer();
+		self.map.draw_background(&mut self.display);
+		let level_string = String::from("LEVEL ") + &self.level.to_string() + ": " + &name;
+		self.display.draw_text(&level_string, rect!(45, 50, 550, 150));
+		for (i, line) in intro.iter().enumerate() {
+			self.display.draw_text(line, rect!(60, 250 + (i * 50), 10 * line.len(), 40));
+		}
+		self.display.draw_text("PRESS ENTER AND START RUNNING...", rect!(160, 500, 300, 50));
+		self.display.switch_buffers();
+	}
+
 	pub fn draw_completion_screen(&mut self) {
-		let level_string = String::from("YOU BEAT LEVEL ") + &self.level.to_string() + "!";
+		let level_string = if self.mode == mode::Mode::Campaign && self.level as usize == self.campaign.len() {
+			String::from("YOU BEAT THE CAMPAIGN!")
+		} else {
+			String::from("YOU BEAT LEVEL ") + &self.level.to_string() + "!"
+		};
 		self.display.draw_text(&level_string, rect!(45, 100, 550, 200));
 		let score_string = String::from("YOUR SCORE: ") + &self.score.to_string();
 		self.display.draw_text(&score_string, rect!(120, 300, 400, 100));
@@ -400,61 +714,190 @@ impl<'e> Game<'e> {
 			let score = self.score;
 			self.store_highscore(score);
 		}
+		self.finish_daily_attempt();
+		self.display.draw_text("PRESS ENTER TO RUN SOME MORE...", rect!(160, 500, 300, 50));
+		self.display.switch_buffers();
+	}
+
+	/// Shows how the finished survival run went and where it placed.
+	pub fn draw_survival_results(&mut self, place: Option<usize>) {
+		let entry = self.survival.entry();
+		let survived_string = String::from("SURVIVED ") + &self.survival.seconds().to_string() + "s WITH "
+			+ &entry.kills.to_string() + " KILLS";
+		self.display.draw_text(&survived_string, rect!(120, 300, 400, 60));
+		let score_string = String::from("YOUR SCORE: ") + &entry.score.to_string();
+		self.display.draw_text(&score_string, rect!(120, 360, 400, 60));
+		match place {
+			Some(1) => { self.display.draw_text("NEW HIGHSCORE!!", rect!(120, 420, 400, 60)); },
+			Some(place) => {
+				let place_string = String::from("LEADERBOARD #") + &place.to_string();
+				self.display.draw_text(&place_string, rect!(120, 420, 400, 60));
+			},
+			None => {}
+		}
 		self.display.draw_text("PRESS ENTER TO RUN SOME MORE...", rect!(160, 500, 300, 50));
 		self.display.switch_buffers();
 	}
 
 	pub fn set_score_and_timer(&mut self) {
-		self.score = self.score + (self.level * 1000) + (self.player.get_health() as i32 * 1000) + self.timer;
+		let health_bonus = (self.player.get_health() as i32 * HEALTH_SCORE) / self.player.get_max_health() as i32;
+		self.score = self.score + (self.level * 1000) + health_bonus + self.timer;
 		self.timer = self.timer + LEVEL_2_TIME;
 	}
 
 	pub fn restart(&mut self) {
 		println!("Restarting game...");
 		self.level = 0;
-		self.new_level();
-		self.paused = true;
+		if self.mode == mode::Mode::Campaign {
+			self.level = self.campaign_selected as i32 - 1;
+		}
+		if self.mode == mode::Mode::Daily {
+			self.daily = daily::Challenge::today();
+		}
+		self.inventory.clear();
+		self.powerup_loot = loot::LootTable::powerups(&self.loot_tables);
+		for table in self.zombie_loot.iter_mut() {
+			table.reset_pity();
+		}
+		self.trap_kits = vec![0; TRAP_KINDS];
+		self.selected_trap = 0;
 		self.score = 0;
 		self.timer = LEVEL_1_TIME;
+		self.new_level();
+		self.paused = true;
+		self.in_menu = true;
+	}
+
+	/// Uses up today's scored attempt if it hasn't been used yet. It counts
+	/// from the moment the run starts, so quitting doesn't earn another go.
+	pub fn start_daily_attempt(&mut self) {
+		let date = self.daily.date.clone();
+		self.daily_scored = self.daily_results.get(&date).is_none();
+		if self.daily_scored {
+			self.daily_results.record(&date, leaderboard::Entry { score: 0, frames: 0, kills: 0 });
+		}
+	}
+
+	/// Stores the result of a scored daily attempt once the run is over.
+	pub fn finish_daily_attempt(&mut self) {
+		if self.mode != mode::Mode::Daily || !self.daily_scored {
+			return;
+		}
+		let date = self.daily.date.clone();
+		let entry = leaderboard::Entry { score: self.score, frames: self.updates, kills: self.kills };
+		self.daily_results.record(&date, entry);
+		self.daily_scored = false;
+	}
+
+	/// The daily modifiers in play, none outside the daily challenge.
+	fn modifiers(&self) -> daily::Modifiers {
+		match self.mode {
+			mode::Mode::Daily => self.daily.modifiers,
+			_                 => daily::Modifiers::none()
+		}
+	}
+
+	/// Unlocks the campaign level after the one just beaten and saves it.
+	pub fn unlock_next_level(&mut self) {
+		let next = cmp::min(self.level as usize + 1, self.campaign.len());
+		if next > self.campaign_unlocked {
+			self.campaign_unlocked = next;
+			campaign::store_progress(next);
+		}
+		self.campaign_selected = next;
+	}
+
+	/// Moves on once a level is beaten, back to the title screen when there
+	/// are no campaign levels left.
+	pub fn advance_level(&mut self) {
+		if self.mode == mode::Mode::Daily {
+			// the daily challenge is a single level
+			self.restart();
+			self.draw_start_screen();
+		} else if self.mode != mode::Mode::Campaign {
+			self.new_level();
+		} else if self.level as usize >= self.campaign.len() {
+			self.restart();
+			self.draw_start_screen();
+		} else {
+			self.new_level();
+			self.paused = true;
+			self.draw_intro_screen();
+		}
 	}
 
 	pub fn new_level(&mut self) {
 		println!("Starting new level...");
 		self.level = self.level + 1;
 
-		let mut rng = rand::thread_rng();
+		// daily challenges build the same level for everybody on the day
+		let seed = match self.mode {
+			mode::Mode::Daily => self.daily.seed,
+			_                 => rand::thread_rng().gen()
+		};
+		self.level_rng = StdRng::seed_from_u64(seed);
+
 		let enemies_vector: Vec<Box<dyn enemies::Zombie>> = Vec::new();
 		let powerup_vector: Vec<Box<dyn powerups::Powerup>> = Vec::new();
 		let traps_vector: Vec<Box<dyn traps::Trap>> = Vec::new();
 		let killed_vector: Vec<Box<dyn enemies::Zombie>> = Vec::new();
 		let activated_vector: Vec<Box<dyn powerups::Powerup>> = Vec::new();
 		let tripped_vector: Vec<Box<dyn traps::Trap>> = Vec::new();
-		let coll_part_vector: Vec<Box<dyn vehicle::Part>> = Vec::new();
 		let part_vector: Vec<Box<dyn vehicle::Part>> = Vec::new();
 
+		// max health upgrades last for the whole run
+		let max_health = self.player.get_max_health();
 		self.player = player::Player::new(
 				&mut self.display,
 				PLAYER_STARTING_X.to_game(),
 				PLAYER_STARTING_Y.to_game()
 			);
+		if self.level > 1 {
+			self.player.set_max_health(max_health);
+		}
+		let partner_max_health = self.partner.as_ref().map(|partner| partner.get_max_health());
+		self.partner = if self.coop {
+			let mut partner = player::Player::new(
+				&mut self.display,
+				PARTNER_STARTING_X.to_game(),
+				PARTNER_STARTING_Y.to_game()
+			);
+			match partner_max_health {
+				Some(max_health) if self.level > 1 => { partner.set_max_health(max_health); },
+				_ => {}
+			}
+			Some(partner)
+		} else {
+			None
+		};
 
-		let vehicle_num = rng.gen_range(0..2);
-		self.vehicle = match vehicle_num {
-			0 => {
-				Box::new( heli::Helicopter::new(
-					&mut self.display, 
-					VEHICLE_STARTING_X.to_game(), 
-					VEHICLE_STARTING_Y.to_game()
-				) ) as Box<dyn vehicle::Vehicle>
-			}, 
+		// campaign levels pick their own layout, vehicle and zombies
+		let (layout, vehicle_num, mix) = match self.mode {
+			mode::Mode::Campaign => {
+				let level = &self.campaign[self.level as usize - 1];
+				(level.map.clone(), level.vehicle, level.zombies)
+			},
+			mode::Mode::Daily => {
+				let mix = if self.daily.modifiers.crazy_only { daily::CRAZY_MIX } else { director::DEFAULT_MIX };
+				(map::Layout::Scattered, self.level_rng.gen_range(0..self.vehicle_definitions.len()), mix)
+			},
 			_ => {
-				Box::new( car::Car::new(
-					&mut self.display, 
-					VEHICLE_STARTING_X.to_game(), 
-					VEHICLE_STARTING_Y.to_game()
-				) ) as Box<dyn vehicle::Vehicle>
+				let layout = if self.level == 1 { map::Layout::Open } else { map::Layout::Scattered };
+				(layout, self.level_rng.gen_range(0..self.vehicle_definitions.len()), director::DEFAULT_MIX)
 			}
 		};
+		if self.mode == mode::Mode::Campaign {
+			let level = &self.campaign[self.level as usize - 1];
+			self.powerup_loot = loot::LootTable::named(&self.loot_tables, &level.powerups).unwrap();
+			self.timer = level.time;
+		}
+		self.vehicle = Box::new( vehicle::DataVehicle::new(
+			&mut self.display,
+			&self.vehicle_definitions,
+			vehicle_num,
+			VEHICLE_STARTING_X.to_game(),
+			VEHICLE_STARTING_Y.to_game()
+		) ) as Box<dyn vehicle::Vehicle>;
 
 		self.enemies = enemies_vector;
 		self.powerups = powerup_vector;
@@ -462,32 +905,59 @@ impl<'e> Game<'e> {
 		self.killed = killed_vector;
 		self.activated = activated_vector;
 		self.tripped = tripped_vector;
-		self.coll_parts = coll_part_vector;
 		self.parts = part_vector;
-		let number_of_zombies = rng.gen_range(20u32..MAX_ENEMIES);
-		for _ in 0.. number_of_zombies {
-		  	self.spawn_zombie(rng.gen_range(1u32..5u32), (units::Game(0.0), units::Game(0.0)));
+		self.noises = Vec::new();
+		self.projectiles = Vec::new();
+		self.part_pickup_timer = 0;
+		self.traps_placed = 0;
+		self.kills = 0;
+
+		// create new level map
+		let new_map = map::Map::load_map(&mut self.display, &layout, seed);
+		self.map = new_map;
+		self.map.set_page(self.player.character.map_center_x(), self.player.character.map_center_y());
+
+		// the director starts every level calm, so powerups are weighted by
+		// how the previous level ended
+		let difficulty = if self.level > 1 { self.director.difficulty() } else { loot::CALM };
+
+		// survival brings its own opening crowd
+		let mut kinds = self.director.start_level(&mut self.level_rng, self.level, mix);
+		if self.mode == mode::Mode::Survival {
+			kinds = self.survival.start();
+		}
+		for kind in kinds {
+			let location = self.director.scatter_location(&mut self.level_rng, &self.map);
+			self.spawn_zombie(kind, location);
+		}
+		if !self.modifiers().no_powerups {
+			self.spawn_level_powerups(difficulty);
+		}
+		let level_traps = match self.mode {
+			mode::Mode::Campaign => self.campaign[self.level as usize - 1].traps.clone(),
+			_                    => Vec::new()
+		};
+		if level_traps.is_empty() {
+			let number_of_traps = self.level_rng.gen_range(0u32..MAX_TRAPS);
+			for _ in 0.. number_of_traps {
+				let kind = LEVEL_TRAP_KINDS[self.level_rng.gen_range(0..LEVEL_TRAP_KINDS.len())];
+				self.spawn_trap(kind);
+			}
 		}
-		let number_of_powerups = rng.gen_range(0u32..MAX_POWERUPS);
-		for _ in 0.. number_of_powerups {
-			self.spawn_powerup(rng.gen_range(1u32..7u32));
+		for (kind, x, y) in level_traps {
+			if !self.spawn_trap_at(kind, units::Tile(x), units::Tile(y)) {
+				println!("skipping trap at {}, {}: blocked by a wall", x, y);
+			}
 		}
-		// let number_of_traps = rng.gen_range(0u, MAX_TRAPS);
-		// for _ in range(0, number_of_traps) {
-		// 	self.spawn_trap(1);
-		// }
 
-		for i in 0.. LEVEL_PARTS {
-			self.spawn_part(i);
+		if self.mode.has_vehicle() {
+			for i in 0.. self.vehicle.part_count() {
+				self.spawn_part(i);
+			}
 		}
 
 		self.paused = false;
 		self.updates = 0;
-		self.freeze_counter = 0;
-
-		// create new level map
-		let new_map = map::Map::load_map(&mut self.display, self.level);
-		self.map = new_map;
 	}
 
 	/// Polls current input events & dispatches them to the engine.
@@ -530,10 +1000,47 @@ impl<'e> Game<'e> {
 				running = false;
 			}
 
+			// Handle switching modes before a run starts
+			if self.in_menu && self.controller.was_key_released(Keycode::M) {
+				self.mode = self.mode.next();
+				self.restart();
+				self.draw_start_screen();
+			}
+
+			// Handle turning co-op on and off before a run starts
+			if self.in_menu && self.controller.was_key_released(Keycode::P) {
+				self.coop = !self.coop;
+				self.restart();
+				self.draw_start_screen();
+			}
+
+			// Handle picking an unlocked campaign level
+			if self.in_menu && self.mode == mode::Mode::Campaign {
+				let selected = self.campaign_selected;
+				if self.controller.was_key_released(Keycode::Left) && selected > 1 {
+					self.campaign_selected = selected - 1;
+				}
+				if self.controller.was_key_released(Keycode::Right) && selected < self.campaign_unlocked {
+					self.campaign_selected = selected + 1;
+				}
+				if self.campaign_selected != selected {
+					self.restart();
+					self.draw_start_screen();
+				}
+			}
+
 			// Handle paused game
 			if self.controller.was_key_released(Keycode::Return) {
-				if self.paused {
+				if self.in_menu && self.mode == mode::Mode::Campaign {
+					// brief the player before the first level
+					self.in_menu = false;
+					self.draw_intro_screen();
+				} else if self.paused {
+					if self.in_menu && self.mode == mode::Mode::Daily {
+						self.start_daily_attempt();
+					}
 					self.paused = false;
+					self.in_menu = false;
 					self.music.resume_music();
 				} else {
 					self.paused = true;
@@ -546,6 +1053,25 @@ impl<'e> Game<'e> {
 				self.alt_control = !self.alt_control;
 			}
 
+			// Handle sprinting
+			if self.controller.is_key_held(Keycode::Z) {
+				self.player.start_sprinting();
+			} else {
+				self.player.stop_sprinting();
+			}
+
+			// Handle dodging
+			if !self.paused && !self.player.is_down() && self.controller.was_key_pressed(Keycode::X) {
+				self.player.start_dodge();
+			}
+
+			// Handle driving the finished vehicle
+			if self.vehicle.is_boarded() {
+				let x_direction = Game::key_direction(self.controller.is_key_held(Keycode::Left), self.controller.is_key_held(Keycode::Right));
+				let y_direction = Game::key_direction(self.controller.is_key_held(Keycode::Up), self.controller.is_key_held(Keycode::Down));
+				self.vehicle.steer(x_direction, y_direction);
+			}
+
 			// Handle player movement
 			if self.controller.was_key_released(Keycode::Left) || self.controller.was_key_released(Keycode::Right) {
 				self.player.stop_moving_horizontally(self.alt_control);
@@ -571,13 +1097,99 @@ impl<'e> Game<'e> {
 				self.player.start_moving_down();
 			}
 
+			// Handle weapons
+			if self.controller.was_key_pressed(Keycode::Q) {
+				self.player.switch_weapon();
+			}
+			if !self.paused && self.controller.is_key_held(Keycode::Space) {
+				self.use_weapon();
+			}
+
+			// Handle traps
+			if self.controller.was_key_pressed(Keycode::V) {
+				self.select_next_trap();
+			}
+			if !self.paused && self.controller.was_key_pressed(Keycode::C) {
+				self.place_trap();
+			}
+
+			// Handle the second player, on WASD with their own action keys
+			if let Some(ref mut partner) = self.partner {
+				if self.controller.is_key_held(Keycode::H) {
+					partner.start_sprinting();
+				} else {
+					partner.stop_sprinting();
+				}
+				if !self.paused && !partner.is_down() && self.controller.was_key_pressed(Keycode::G) {
+					partner.start_dodge();
+				}
+
+				if self.controller.was_key_released(Keycode::A) || self.controller.was_key_released(Keycode::D) {
+					partner.stop_moving_horizontally(self.alt_control);
+				}
+				if self.controller.is_key_held(Keycode::A) && self.controller.is_key_held(Keycode::D) {
+					partner.stop_moving_horizontally(self.alt_control);
+				} else if self.controller.is_key_held(Keycode::A) {
+					partner.start_moving_left();
+				} else if self.controller.is_key_held(Keycode::D) {
+					partner.start_moving_right();
+				} else {
+					partner.stop_moving_horizontally(self.alt_control);
+				}
+
+				if self.controller.was_key_released(Keycode::W) || self.controller.was_key_released(Keycode::S) {
+					partner.stop_moving_vertically(self.alt_control);
+				}
+				if self.controller.is_key_held(Keycode::W) && self.controller.is_key_held(Keycode::S) {
+					partner.stop_moving_vertically(self.alt_control);
+				} else if self.controller.is_key_held(Keycode::W) {
+					partner.start_moving_up();
+				} else if self.controller.is_key_held(Keycode::S) {
+					partner.start_moving_down();
+				}
+
+				if self.controller.was_key_pressed(Keycode::R) {
+					partner.switch_weapon();
+				}
+			}
+			if !self.paused && self.controller.is_key_held(Keycode::F) {
+				self.as_partner(|game| game.use_weapon());
+			}
+			if self.partner.is_some() && self.controller.was_key_pressed(Keycode::Tab) {
+				self.select_next_trap();
+			}
+			if !self.paused && self.controller.was_key_pressed(Keycode::E) {
+				self.as_partner(|game| game.place_trap());
+			}
+
+			// Handle using stored powerups, either player can use the team's items
+			if !self.paused {
+				for slot in 0.. self.inventory.get_capacity() {
+					match Game::slot_key(slot) {
+						Some(key) if self.controller.was_key_pressed(key) => { self.use_powerup(slot); },
+						_ => {}
+					}
+					match Game::partner_slot_key(slot) {
+						Some(key) if self.controller.was_key_pressed(key) => { self.as_partner(|game| game.use_powerup(slot)); },
+						_ => {}
+					}
+				}
+			}
+
 			// inform actors of how much time has passed since last frame
 			let current_time_ms = Game::time_since(start_time);
 			let elapsed_time    = current_time_ms - last_update_time;
 		
 			// only update if not in paused state
 			if !self.paused {
-				self.update(cmp::min(elapsed_time, MAX_FRAME_TIME));
+				// double speed runs the world twice a frame
+				let steps = if self.modifiers().double_speed { 2 } else { 1 };
+				for _ in 0.. steps {
+					if self.paused || self.completed_lvl {
+						break;
+					}
+					self.update(cmp::min(elapsed_time, MAX_FRAME_TIME));
+				}
 				last_update_time = current_time_ms;
 
 				// draw if game has been started
@@ -589,7 +1201,9 @@ impl<'e> Game<'e> {
 				}
 
 				// decrement timer
-				self.timer = self.timer - 1;	
+				if self.mode.is_timed() {
+					self.timer -= steps;
+				}
 			}
 
 			// throttle event-loop based on iteration time vs frame deadline
@@ -603,14 +1217,10 @@ impl<'e> Game<'e> {
 
 			std::thread::sleep_ms(next_frame_time as u32);
 
-			if (self.completed_lvl && running) {
-				let mut cinematic_counter = LEVEL_1_CINEMATIC_FRAMES;
+			if self.completed_lvl && running {
+				let mut show_completion_screen = true;
 				while self.completed_lvl && running {
 					let start_time_ms = units::Millis(Instant::now().elapsed().as_millis());
-					// inform actors of how much time has passed since last frame
-					let current_time_ms = Game::time_since(start_time);
-					let elapsed_time    = current_time_ms - last_update_time;
-					let show_completion_screen = true;
 
 					self.controller.begin_new_frame();
 
@@ -633,26 +1243,17 @@ impl<'e> Game<'e> {
 						self.completed_lvl = false;
 					}
 
-					// Handle paused game
+					// Handle next level
 					if self.controller.was_key_released(Keycode::Return) {
-						if cinematic_counter < 0 {
-							self.completed_lvl = false;
-							self.new_level();
-							break;
-						} 
+						self.completed_lvl = false;
+						self.advance_level();
+						last_update_time = Game::time_since(start_time);
+						break;
 					}
-				
-					// only update if not in paused state
-					self.update_cinematic(cmp::min(elapsed_time, MAX_FRAME_TIME));
-					last_update_time = current_time_ms;
-
-					if cinematic_counter > 0 {
-						self.display.clear_buffer(); // clear back-buffer
-						self.draw_cinematic();
-						self.draw_zombies();
-						self.display.switch_buffers();
-					} else if show_completion_screen {
+
+					if show_completion_screen {
 						self.draw_completion_screen();
+						show_completion_screen = false;
 					}
 
 					// throttle event-loop based on iteration time vs frame deadline
@@ -663,8 +1264,6 @@ impl<'e> Game<'e> {
 					} else { 0 as u64 };
 					
 					std::thread::sleep_ms(next_frame_time as u32);
-
-					cinematic_counter = cinematic_counter - 1;
 				}
 			}
 
@@ -686,7 +1285,7 @@ impl<'e> Game<'e> {
 		self.map.draw_background(&mut self.display);
 
 		// foreground
-		if self.map.on_screen(self.vehicle.get_map_x(), self.vehicle.get_map_y()) {
+		if self.mode.has_vehicle() && self.map.on_screen(self.vehicle.get_map_x(), self.vehicle.get_map_y()) {
 			self.vehicle.draw(&mut self.display);
 		}
 		for part in self.parts.iter() {
@@ -699,13 +1298,32 @@ impl<'e> Game<'e> {
 				powerup.draw(&mut self.display); 
 			} 
 		}
+		self.draw_debuff_warnings();
 		for trap in self.traps.iter() { 
 			if self.map.on_screen(trap.get_map_x(), trap.get_map_y()) { 
 				trap.draw(&mut self.display); 
 			} 
 		}
+		self.draw_trap_preview();
+		self.as_partner(|game| game.draw_trap_preview());
 		self.draw_zombies();
-		self.player.draw(&mut self.display);
+		for projectile in self.projectiles.iter() {
+			if self.map.on_screen(projectile.get_map_x(), projectile.get_map_y()) {
+				projectile.draw(&mut self.display);
+			}
+		}
+		if !self.vehicle.is_boarded() {
+			self.player.draw(&mut self.display);
+			match self.partner {
+				Some(ref partner) if self.map.on_screen(partner.character.map_x, partner.character.map_y) => {
+					partner.draw(&mut self.display);
+					// mark player two so the pair can be told apart
+					let (units::Game(x), units::Game(y)) = (partner.character.x + units::HalfTile(1).to_game(), partner.character.y);
+					self.display.draw_arrow((x as i32, y as i32), (0.0, 1.0), PARTNER_MARKER_SIZE, PARTNER_MARKER_COLOR);
+				},
+				_ => {}
+			}
+		}
 		let mut kill_list: Vec<Box<dyn enemies::Zombie>> = Vec::new();
 		let mut active_list: Vec<Box<dyn powerups::Powerup>> = Vec::new();
 		let mut tripped_list: Vec<Box<dyn traps::Trap>> = Vec::new();
@@ -713,15 +1331,7 @@ impl<'e> Game<'e> {
 			match self.activated.pop() {
 				Some(activated) => {
 					let mut mut_activated = activated;
-					// draw 'bullet' for kill zombie 
-					if mut_activated.get_type() == 2 {
-						let killed_enemy = self.killed.get(self.killed.len() - 1).unwrap();
-						let (units::Game(player_x), units::Game(player_y)) = (self.player.character.center_x(), self.player.character.center_y());
-						let (units::Game(enemy_x), units::Game(enemy_y)) = (killed_enemy.get_map_x(), killed_enemy.get_map_y());
-						self.display.draw_line( (player_x as i32, player_y as i32), (enemy_x as i32, enemy_y as i32) );
-					} else {
-						mut_activated.draw(&mut self.display);
-					}
+					mut_activated.draw(&mut self.display);
 					if !mut_activated.is_finished() {
 						active_list.push(mut_activated);
 					}
@@ -759,6 +1369,13 @@ impl<'e> Game<'e> {
 		self.activated = active_list;
 		self.tripped = tripped_list;
 		self.map.draw(&mut self.display);
+		let from = (self.player.character.map_center_x(), self.player.character.map_center_y());
+		self.compass.draw(&mut self.display, &self.map, from);
+
+		if self.player.character.effects.has(status::Effect::Blinded) {
+			let (units::Game(x), units::Game(y)) = (self.player.character.x + units::HalfTile(1).to_game(), self.player.character.y + units::HalfTile(1).to_game());
+			self.display.draw_darkness(x as i32, y as i32, DARKNESS_RADIUS);
+		}
 	}
 
 	fn draw_zombies(&mut self) {
@@ -769,69 +1386,144 @@ impl<'e> Game<'e> {
 		}
 	}
 
-	fn draw_cinematic(&mut self) {
-		// background
-		self.map.draw_background(&mut self.display);
-		self.map.draw(&mut self.display);
-		self.vehicle.update_for_cinematic();
-		self.vehicle.draw(&mut self.display);
-	}
-
 	/// Passes the current time in milliseconds to our underlying actors.
 	fn update(&mut self, elapsed_time: units::Millis) {
-		self.map.update();
-		if self.freeze_counter == 0 {
-			for i in 0u32.. self.enemies.len() as u32 { 
-				let enemy = self.enemies.get_mut(i as usize).unwrap();
-				let (player_x, player_y) = self.player.get_follow_coords();
-				enemy.set_acceleration(player_x, player_y); 
-				enemy.update(elapsed_time, &self.map); 
-			}
-		} else {
-			self.freeze_counter = self.freeze_counter - 1;
+		if self.vehicle.is_boarded() {
+			self.update_escape(elapsed_time);
+			return;
 		}
-		self.player.update(elapsed_time, &self.map);
-		self.map.set_page(self.player.character.map_center_x(), self.player.character.map_center_y());
+		self.map.update();
+		self.alert_zombies();
+		let vehicle_target = self.vehicle_target();
+		let units::Game(aggro_radius) = VEHICLE_AGGRO_RADIUS.to_game();
+		for i in 0u32.. self.enemies.len() as u32 { 
+			let enemy = self.enemies.get_mut(i as usize).unwrap();
+			let scent = match vehicle_target {
+				Some((units::Game(x), units::Game(y))) => {
+					let units::Game(dx) = enemy.get_map_x() - units::Game(x);
+					let units::Game(dy) = enemy.get_map_y() - units::Game(y);
+					if (dx * dx + dy * dy).sqrt() < aggro_radius {
+						vehicle_target
+					} else {
+						Game::nearest_player(&self.player, &self.partner, enemy.get_map_x(), enemy.get_map_y())
+							.track_scent(&self.map, enemy.get_map_x() + units::HalfTile(1).to_game(), enemy.get_map_y() + units::HalfTile(1).to_game())
+					}
+				},
+				None => Game::nearest_player(&self.player, &self.partner, enemy.get_map_x(), enemy.get_map_y())
+					.track_scent(&self.map, enemy.get_map_x() + units::HalfTile(1).to_game(), enemy.get_map_y() + units::HalfTile(1).to_game())
+			};
+			enemy.set_acceleration(scent); 
+			enemy.update(elapsed_time, &self.map); 
+		}
+		self.attack_vehicle();
+		self.zombies_spit();
+		self.update_zombie_effects();
+		self.update_barricades();
+		let partner_position = self.partner.as_ref().and_then(|partner| Game::holding_position(&self.map, partner));
+		self.move_player(elapsed_time, partner_position);
+		let player_position = Game::holding_position(&self.map, &self.player);
+		self.as_partner(|game| game.move_player(elapsed_time, player_position));
+		self.follow_players();
 		for i in 0.. self.killed.len() { self.killed.get_mut(i).unwrap().update(elapsed_time, &self.map) }
 		for i in 0.. self.activated.len() { self.activated.get_mut(i).unwrap().update(elapsed_time, &self.map) }
 		for i in 0.. self.tripped.len() { self.tripped.get_mut(i).unwrap().update(elapsed_time, &self.map) }
-		for i in 0.. self.powerups.len() {
-			let powerup = self.powerups.get_mut(i);
-		    // change debuff status every 10 updates
-			if self.updates % 20 == 0 {
-				powerup.unwrap().toggle_debuff();
-			}
+		for i in 0.. self.traps.len() { self.traps.get_mut(i).unwrap().update(elapsed_time, &self.map) }
+		if self.updates % DEBUFF_ROLL_INTERVAL == 0 {
+			self.roll_debuffs();
 		}
 		self.vehicle.update(elapsed_time);
 
-		let mut collided_with_zombie = false;
-		if !self.player.is_immune() {
-			for i in 0.. self.enemies.len() { 
-				if self.enemies.get(i).unwrap().damage_rectangle().collides_with_player(&self.player.character.damage_rectangle()) {
-					if self.player.has_bat() || self.player.is_teleporting() {
-						let enemy = self.enemies.remove(i);
-						self.music.play_sound_effect(6);
-						let mut mut_enemy = enemy;
-						mut_enemy.kill_zombie();
-						self.killed.push(mut_enemy);
-						self.player.take_bat();
-						self.timer = self.timer + 100;
-					}
-				 	else {
-						collided_with_zombie = true;
-				 	}
-				 	break;
+		// Move projectiles and resolve shots at zombies
+		self.update_projectiles(elapsed_time);
+
+		if self.part_pickup_timer > 0 {
+			self.part_pickup_timer -= 1;
+		}
+		self.collect_items();
+		self.as_partner(|game| game.collect_items());
+
+		// Activate traps zombies walked into, the players' own are sprung below
+		self.spring_zombie_traps();
+		let blasts = self.detonate_landmines();
+		self.release_zombies();
+
+		if self.vehicle.is_built() && !self.vehicle.is_boarded() {
+			println!("ESCAPE!");
+			self.music.play_sound_effect(7);
+			self.vehicle.board();
+		}
+
+		// ran out of time
+		if self.mode.is_timed() && self.timer <= 0 {
+			self.game_over();
+		}
+
+		// both players are hurt the same way, the game is over once neither is up
+		let player_down = self.resolve_player_hits(&blasts);
+		let partner_down = self.as_partner(|game| game.resolve_player_hits(&blasts)).unwrap_or(false);
+		if player_down || partner_down {
+			if self.player.is_down() && !self.partner_is_up() {
+				self.draw();
+				// draw game over screen store score and start a new game
+				self.game_over();
+				return;
+			}
+			println!("{} DOWN", if player_down { "PLAYER ONE" } else { "PLAYER TWO" });
+		}
+		self.revive_players();
+
+		self.update_compass();
+
+		// let the director pace new waves, survival keeps the director
+		// around for its stress estimate but escalates on its own schedule
+		let part_distance = self.objective_distance();
+		let mut new_kinds = self.director.update(&mut self.level_rng, self.player.get_health(), self.enemies.len(), part_distance);
+		if self.mode == mode::Mode::Survival {
+			new_kinds = self.survival.update(self.enemies.len());
+			if self.survival.powerup_due(self.powerups.len()) {
+				let difficulty = self.director.difficulty();
+				if let Some(kind) = self.powerup_loot.roll(&mut self.level_rng, self.level, difficulty) {
+					self.spawn_powerup(kind);
+				}
+			}
+		}
+		for kind in new_kinds {
+			let location = self.director.spawn_location(&mut self.level_rng, &self.map);
+			self.spawn_zombie(kind, location);
+		}
+
+		// populate cloud zombies
+		if self.updates != 0 && self.director.can_spawn(self.enemies.len()) {
+			let mut new_zombies = false;
+			let mut zombie_location: (units::Game, units::Game) = (units::Game(0.0), units::Game(0.0));
+			for enemy in self.enemies.iter() { 
+				if enemy.zombie_type() == 4 && self.updates % 300 == 0 {
+				  new_zombies = true;
+				  zombie_location = enemy.get_target();
+				  break;
 				}
 			}
+			if new_zombies {
+				self.spawn_zombie(4, zombie_location);
+			}
+		}
+	}
+
+	/// Picks up parts and powerups the player is standing on and hands any
+	/// parts they are carrying over at the vehicle. Each player carries
+	/// their own parts and only they are slowed down by them.
+	fn collect_items(&mut self) {
+		if self.player.is_down() {
+			return;
 		}
 
 		// Collect part
-		if self.coll_parts.len() == 0 {
+		if self.player.get_parts().len() < MAX_CARRIED_PARTS && self.part_pickup_timer == 0 {
 			for i in 0.. self.parts.len() { 
 				if self.parts.get(i).unwrap().damage_rectangle().collides_with(&self.player.character.damage_rectangle()) {
 					let part = self.parts.remove(i);
 					self.music.play_sound_effect(7);
-					self.coll_parts.push(part);
+					self.player.carry_part(part);
 					break;
 				}
 			}
@@ -839,13 +1531,15 @@ impl<'e> Game<'e> {
 
 		// Apply parts to vehicle
 		if self.vehicle.damage_rectangle().collides_with(&self.player.character.damage_rectangle()) {
-			match self.coll_parts.pop() {
-				Some(part) => { self.vehicle.add_part(part.part_type()); },
-				None       => {}
-			};
+			if let Some(part) = self.player.drop_part() {
+				self.vehicle.add_part(part.part_type());
+				let (vehicle_x, vehicle_y) = (self.vehicle.get_map_x(), self.vehicle.get_map_y());
+				self.make_noise(vehicle_x, vehicle_y, noise::PART_INSTALL);
+			}
 		}
 
-		// Apply powerup
+		// Pick up powerup
+		self.attract_powerups();
 		let mut counter = 0;
 		let mut hit_powerup = false;
 		for powerup in self.powerups.iter_mut() { 
@@ -853,145 +1547,807 @@ impl<'e> Game<'e> {
 				hit_powerup = true;
 			 	break;
 			}
-			counter = counter + 1;
+			counter += 1;
 		}
 		if hit_powerup {
-			self.apply_powerup(counter);
+			self.pick_up_powerup(counter);
 		}
+	}
 
-		// Activate traps
-		let mut counter = 0;
-		let mut player_hit_trap = false;
-		let mut zombie_hit_trap = false;
-		for trap in self.traps.iter_mut() { 
-			// check if player hit trap
-			if trap.damage_rectangle().collides_with_player(&self.player.character.damage_rectangle()) {
-				player_hit_trap = true;
-				break;
-			}
-			// check if zombies hit trap
-			for i in 0.. self.enemies.len() {
-				if self.enemies.get(i).unwrap().damage_rectangle().collides_with(&trap.damage_rectangle()) {
-					let enemy = self.enemies.remove(i as usize);
-					let mut mut_enemy = enemy;
-					mut_enemy.kill_zombie();
-					self.killed.push(mut_enemy);
-					zombie_hit_trap = true;
-					break;
+	/// Runs `action` with player two standing in for player one, so anything
+	/// written for a single player works for either. Does nothing outside
+	/// co-op.
+	fn as_partner<F, T>(&mut self, action: F) -> Option<T> where F: FnOnce(&mut Game<'e>) -> T {
+		match self.partner.take() {
+			Some(partner) => {
+				let first = std::mem::replace(&mut self.player, partner);
+				let result = action(self);
+				let partner = std::mem::replace(&mut self.player, first);
+				self.partner = Some(partner);
+				Some(result)
+			},
+			None => None
+		}
+	}
+
+	fn partner_is_up(&self) -> bool {
+		match self.partner {
+			Some(ref partner) => !partner.is_down(),
+			None              => false
+		}
+	}
+
+	/// The player zombies at `x`, `y` should go after: whoever is closest
+	/// and still on their feet.
+	fn nearest_player<'p>(player: &'p player::Player, partner: &'p Option<player::Player>, x: units::Game, y: units::Game) -> &'p player::Player {
+		match *partner {
+			Some(ref partner) if !partner.is_down() && (player.is_down() || partner.character.distance(x, y) < player.character.distance(x, y)) => partner,
+			_ => player
+		}
+	}
+
+	/// Where `player` stands if they are up and on the page in view, which is
+	/// when they hold their partner back from leaving it.
+	fn holding_position(map: &map::Map, player: &player::Player) -> Option<(units::Game, units::Game)> {
+		let (x, y) = (player.character.map_center_x(), player.character.map_center_y());
+		if player.is_down() || !map.on_screen(x, y) {
+			None
+		} else {
+			Some((x, y))
+		}
+	}
+
+	/// Moves `self.player`, who stays put while down, and lets zombies hear
+	/// them sprinting or magnetized. A partner standing at `partner` keeps
+	/// them on the page in view.
+	fn move_player(&mut self, elapsed_time: units::Millis, partner: Option<(units::Game, units::Game)>) {
+		if self.player.is_down() {
+			self.player.stop_moving_horizontally(true);
+			self.player.stop_moving_vertically(true);
+		}
+		let start = (self.player.character.map_x, self.player.character.map_y);
+		self.player.update(elapsed_time, &self.map);
+		if let Some(partner) = partner {
+			self.hold_on_page(start, partner);
+		}
+
+		let (player_x, player_y) = (self.player.character.map_center_x(), self.player.character.map_center_y());
+		if self.player.is_sprinting() && self.updates % SPRINT_NOISE_INTERVAL == 0 {
+			self.make_noise(player_x, player_y, noise::SPRINTING);
+		}
+		if self.player.character.effects.has(status::Effect::Magnetized) && self.updates % MAGNET_PULL_INTERVAL == 0 {
+			self.make_noise(player_x, player_y, noise::MAGNET);
+		}
+	}
+
+	/// Undoes any step `self.player` took from `start` that would leave their
+	/// partner at `partner` behind. Stepping just over an edge is allowed
+	/// when the partner is close to the same edge, so the two can cross over
+	/// to the next page together.
+	fn hold_on_page(&mut self, start: (units::Game, units::Game), partner: (units::Game, units::Game)) {
+		let units::Game(left) = units::Tile(self.map.get_page_x() * 20).to_game();
+		let units::Game(top) = units::Tile(self.map.get_page_y() * 20).to_game();
+		let (start_x, start_y) = start;
+		let (partner_x, partner_y) = partner;
+		let character = &mut self.player.character;
+		if Game::leaves_page(character.map_center_x(), start_x + units::HalfTile(1), left, partner_x) {
+			character.map_x = start_x;
+			character.velocity_x = units::Velocity(0.0);
+		}
+		if Game::leaves_page(character.map_center_y(), start_y + units::HalfTile(1), top, partner_y) {
+			character.map_y = start_y;
+			character.velocity_y = units::Velocity(0.0);
+		}
+	}
+
+	/// Whether moving from `start` to `position` along one axis takes a
+	/// player further over the edge of the page starting at `low` than their
+	/// partner at `partner` allows.
+	fn leaves_page(position: units::Game, start: units::Game, low: f64, partner: units::Game) -> bool {
+		let units::Game(high) = units::Tile(20).to_game();
+		let high = low + high;
+		let units::Game(slack) = PAGE_SLACK.to_game();
+		let units::Game(range) = CROSSING_RANGE.to_game();
+		let (units::Game(position), units::Game(start), units::Game(partner)) = (position, start, partner);
+		let over = |x: f64| if x < low { low - x } else if x > high { x - high } else { 0.0 };
+		let partner_close = (position < low && partner - low < range) || (position > high && high - partner < range);
+		let allowed = if partner_close { slack } else { 0.0 };
+		over(position) > allowed && over(position) > over(start)
+	}
+
+	/// Keeps the camera on the page in view while anybody up is still on it,
+	/// and follows once they have all crossed over to another.
+	fn follow_players(&mut self) {
+		let partner_in_view = match self.partner {
+			Some(ref partner) => Game::holding_position(&self.map, partner).is_some(),
+			None              => false
+		};
+		if partner_in_view || Game::holding_position(&self.map, &self.player).is_some() {
+			return;
+		}
+		let leader = match self.partner {
+			Some(ref partner) if self.player.is_down() && !partner.is_down() => partner,
+			_ => &self.player
+		};
+		self.map.set_page(leader.character.map_center_x(), leader.character.map_center_y());
+	}
+
+	/// Whoever is still up can get the other back on their feet by touch.
+	fn revive_players(&mut self) {
+		let player_rectangle = self.player.character.damage_rectangle();
+		let player_down = self.player.is_down();
+		let mut revived = false;
+		match self.partner {
+			Some(ref mut partner) if partner.character.damage_rectangle().collides_with(&player_rectangle) => {
+				if player_down && !partner.is_down() {
+					self.player.revive(REVIVE_HEALTH);
+					revived = true;
+				} else if partner.is_down() && !player_down {
+					partner.revive(REVIVE_HEALTH);
+					revived = true;
 				}
-			}
-			if zombie_hit_trap {
-				break;
-			}
-			counter = counter + 1;
+			},
+			_ => {}
 		}
-		if player_hit_trap || zombie_hit_trap {
-			self.activate_trap(counter);
+		if revived {
+			println!("REVIVED");
+			self.music.play_sound_effect(3);
 		}
+	}
 
-		if self.vehicle.is_built() {
-			self.set_score_and_timer();
-			self.completed_lvl = true;
+	/// Works out what hurt `self.player` this update: a zombie they ran into,
+	/// spit, a landmine blast, burns or a trap they stepped in. Only the first
+	/// of those lands, the invulnerability it starts soaks up the rest.
+	/// Returns whether it knocked the player down.
+	fn resolve_player_hits(&mut self, blasts: &[(units::Game, units::Game)]) -> bool {
+		let burn = self.update_player_effects();
+		if self.player.is_down() {
+			return false;
 		}
 
-		// ran out of time
-		if self.timer == 0 {
-			self.draw_game_over_screen();
-			self.restart();
+		let mut contact: Option<health::Damage> = None;
+		if !self.player.is_immune() {
+			let rectangle = self.player.character.damage_rectangle();
+			if let Some(i) = self.enemies.iter().position(|enemy| enemy.damage_rectangle().collides_with_player(&rectangle)) {
+				contact = self.resolve_zombie_contact(i);
+			}
+		}
+		// spit and traps are only used up by the hit that lands
+		let mut hit = contact;
+		if hit.is_none() {
+			hit = self.spit_hit();
+		}
+		if hit.is_none() {
+			hit = self.blast_hit(blasts).or(burn);
+		}
+		if hit.is_none() {
+			hit = self.trip_traps();
 		}
 
-		if collided_with_zombie || player_hit_trap {
+		let damage = match hit {
+			Some(damage) => damage,
+			None         => { return false; }
+		};
+		let result = self.player.take_damage(damage);
+		if result != health::DamageResult::Ignored {
 			self.music.play_sound_effect(6);
-			match self.player.get_health() {
-				health if health > 1 => {
-					self.player.hit_player();
-					self.player.start_immunity();
-				},
-				_ => {
-					self.player.character.kill_character();
-					self.draw();
-					// draw game over screen store score and start a new game
-					self.draw_game_over_screen();
-					self.restart();
+			self.director.record_hit();
+		}
+		match result {
+			health::DamageResult::Killed  => true,
+			health::DamageResult::Hurt    => { self.drop_part(); false },
+			health::DamageResult::Ignored => false
+		}
+	}
+
+	/// Deals with zombie `i` running into `self.player`. With the bat out or
+	/// mid-teleport the player knocks the zombie back, otherwise the zombie's
+	/// hit is returned.
+	fn resolve_zombie_contact(&mut self, i: usize) -> Option<health::Damage> {
+		let (enemy_x, enemy_y) = (self.enemies.get(i).unwrap().get_map_x(), self.enemies.get(i).unwrap().get_map_y());
+		let player = (self.player.character.map_center_x(), self.player.character.map_center_y());
+		if !self.player.has_bat() && !self.player.is_teleporting() {
+			return Some(health::Damage::new(CONTACT_DAMAGE, health::DamageType::Contact)
+				.knockback_from((enemy_x, enemy_y), player, CONTACT_KNOCKBACK));
+		}
+
+		self.music.play_sound_effect(6);
+		let damage = health::Damage::new(self.player.contact_damage(), health::DamageType::Melee)
+			.knockback_from(player, (enemy_x, enemy_y), BAT_KNOCKBACK);
+		if !self.damage_zombie(i, damage) {
+			self.enemies.get_mut(i).unwrap().get_effects_mut().apply(status::Effect::Stunned, STUN_FRAMES);
+		}
+		if self.player.has_bat() {
+			self.player.wear_weapon();
+		}
+		self.timer += 100;
+		None
+	}
+
+	/// Takes the first zombie shot to reach `self.player` out of the air.
+	fn spit_hit(&mut self) -> Option<health::Damage> {
+		if self.player.is_immune() {
+			return None;
+		}
+		let rectangle = self.player.character.damage_rectangle();
+		let index = self.projectiles.iter().position(|projectile| {
+			projectile.get_owner() == projectile::Owner::Zombie && !projectile.is_finished()
+				&& projectile.damage_rectangle().collides_with(&rectangle)
+		});
+		index.map(|i| {
+			let mut spit = self.projectiles.remove(i);
+			spit.hit()
+		})
+	}
+
+	/// The hit from the first of this update's landmine `blasts` that caught
+	/// `self.player`.
+	fn blast_hit(&self, blasts: &[(units::Game, units::Game)]) -> Option<health::Damage> {
+		if self.player.is_immune() {
+			return None;
+		}
+		let units::Game(radius) = LANDMINE_RADIUS.to_game();
+		let player = (self.player.character.map_center_x(), self.player.character.map_center_y());
+		blasts.iter()
+			.find(|&&(x, y)| self.player.character.distance(x, y) <= radius)
+			.map(|&center| health::Damage::new(LANDMINE_DAMAGE, health::DamageType::Explosion)
+				.knockback_from(center, player, CONTACT_KNOCKBACK))
+	}
+
+	/// Springs the first armed trap `self.player` walked into, returning the
+	/// hit if it hurts them. The player knows where their own traps are, and
+	/// walks over the rest unharmed while invulnerable.
+	fn trip_traps(&mut self) -> Option<health::Damage> {
+		if self.player.is_immune() {
+			return None;
+		}
+		let rectangle = self.player.character.damage_rectangle();
+		let index = self.traps.iter().position(|trap| {
+			trap.is_armed() && trap.catches_player() && !trap.is_placed()
+				&& trap.damage_rectangle().collides_with_player(&rectangle)
+		});
+		match index {
+			Some(index) if self.activate_trap(index as u32, None) => Some(health::Damage::new(CONTACT_DAMAGE, health::DamageType::Trap)),
+			_ => None
+		}
+	}
+
+	/// Springs the first armed trap a zombie walked into. Barricades and
+	/// traps still resetting are walked over.
+	fn spring_zombie_traps(&mut self) {
+		for t in 0.. self.traps.len() {
+			let rectangle = {
+				let trap = self.traps.get(t).unwrap();
+				if !trap.is_armed() || !trap.catches_zombies() {
+					continue;
 				}
+				trap.damage_rectangle()
+			};
+			if let Some(i) = self.enemies.iter().position(|enemy| enemy.damage_rectangle().collides_with(&rectangle)) {
+				self.activate_trap(t as u32, Some(i));
+				return;
 			}
 		}
+	}
 
-		// populate cloud zombies
-		if self.updates != 0 {
-			let mut new_zombies = false;
-			let mut zombie_location: (units::Game, units::Game) = (units::Game(0.0), units::Game(0.0));
-			for enemy in self.enemies.iter() { 
-				if enemy.zombie_type() == 4 && self.updates % 300 == 0 {
-				  new_zombies = true;
-				  zombie_location = enemy.get_target();
-				  break;
+	/// Records a noise to be heard by nearby zombies on the next update.
+	fn make_noise(&mut self, x: units::Game, y: units::Game, loudness: units::Tile) {
+		self.noises.push(noise::Noise::new(x, y, loudness));
+	}
+
+	/// Sends every zombie that heard a noise since the last update to go and
+	/// investigate it. Later noises win over earlier ones.
+	fn alert_zombies(&mut self) {
+		for noise in self.noises.iter() {
+			for enemy in self.enemies.iter_mut() {
+				if noise.is_heard_at(enemy.get_map_x(), enemy.get_map_y()) {
+					enemy.investigate(noise.x, noise.y);
 				}
 			}
-			if new_zombies {
-				self.spawn_zombie(4, zombie_location);
+		}
+		self.noises.clear();
+	}
+
+	/// Deals `damage` to the zombie at `index`, moving it to the killed list
+	/// when the hit is fatal. Returns whether the zombie died.
+	fn damage_zombie(&mut self, index: usize, damage: health::Damage) -> bool {
+		let result = self.enemies.get_mut(index).unwrap().take_damage(damage);
+		if result == health::DamageResult::Killed {
+			let enemy = self.enemies.remove(index);
+			self.drop_loot(enemy.zombie_type(), enemy.get_map_x(), enemy.get_map_y());
+			self.killed.push(enemy);
+			self.director.record_kills(1);
+			self.survival.record_kills(1);
+			self.kills += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Rolls the drop table for a killed zombie of `zombie_type` and leaves
+	/// whatever comes up where it died.
+	fn drop_loot(&mut self, zombie_type: i32, x: units::Game, y: units::Game) {
+		if self.modifiers().no_powerups {
+			return;
+		}
+		let difficulty = self.director.difficulty();
+		let level = self.level;
+		let drop = match self.zombie_loot.get_mut((zombie_type - 1) as usize) {
+			Some(table) => table.roll(&mut self.level_rng, level, difficulty),
+			None        => None
+		};
+		match drop {
+			// time bonuses are no use without a level timer
+			Some(15) if !self.mode.is_timed() => {},
+			Some(kind) => {
+					let powerup = self.create_powerup(kind, x - units::HalfTile(1), y - units::HalfTile(1));
+				self.powerups.push(powerup);
+			},
+			None => {}
+		}
+	}
+
+	/// Draws uncursed powerups close to the player in towards them, as long
+	/// as no wall is in the way.
+	fn attract_powerups(&mut self) {
+		let (player_x, player_y) = (self.player.character.map_center_x(), self.player.character.map_center_y());
+		let (units::Game(player_x), units::Game(player_y)) = (player_x, player_y);
+		let units::Game(radius) = MAGNET_RADIUS.to_game();
+		let units::Game(speed) = MAGNET_SPEED;
+		for powerup in self.powerups.iter_mut() {
+			if powerup.is_debuff() {
+				continue;
+			}
+			let (units::Game(x), units::Game(y)) = (powerup.get_map_x(), powerup.get_map_y());
+			let (dx, dy) = (player_x - x, player_y - y);
+			let distance = (dx * dx + dy * dy).sqrt();
+			let in_sight = self.map.line_of_sight((units::Game(x), units::Game(y)), (units::Game(player_x), units::Game(player_y)));
+			if distance > 0.0 && distance < radius && in_sight {
+				let step = if distance < speed { distance } else { speed };
+				powerup.move_by(units::Game(dx / distance * step), units::Game(dy / distance * step));
+			}
+		}
+	}
+
+	/// Attacks with the player's current weapon if it is ready. Guns fire a
+	/// bullet the way the player is aiming, melee weapons hit every zombie in
+	/// reach in front of the player.
+	fn use_weapon(&mut self) {
+		if self.player.is_down() {
+			return;
+		}
+		let attack = match self.player.get_weapon_mut() {
+			Some(weapon) => weapon.attack(),
+			None         => None
+		};
+		let origin = (self.player.character.map_center_x(), self.player.character.map_center_y());
+		let (player_x, player_y) = origin;
+		let (aim_x, aim_y) = self.player.aim_direction();
+
+		match attack {
+			Some(weapons::Attack::Shot(damage, units::Game(range))) => {
+				self.music.play_sound_effect(0);
+				self.make_noise(player_x, player_y, noise::GUNFIRE);
+				let units::Velocity(speed) = BULLET_SPEED;
+				let lifetime = (range / (speed * (1000 / TARGET_FRAMERATE) as f64)) as i32;
+				let bullet = projectile::Projectile::new(&mut self.display, player_x, player_y, projectile::Shot {
+					direction: (aim_x, aim_y),
+					speed:     BULLET_SPEED,
+					damage:    health::Damage::new(damage, health::DamageType::Bullet),
+					owner:     projectile::Owner::Player,
+					lifetime,
+					frame:     projectile::BULLET_FRAME
+				});
+				self.projectiles.push(bullet);
+			},
+			Some(weapons::Attack::Swing(damage, units::Game(range))) => {
+				let mut connected = false;
+				let units::Game(half_tile) = units::HalfTile(1).to_game();
+				for i in (0.. self.enemies.len()).rev() {
+					let enemy = (self.enemies.get(i).unwrap().get_map_x() + units::HalfTile(1).to_game(),
+					             self.enemies.get(i).unwrap().get_map_y() + units::HalfTile(1).to_game());
+					let (units::Game(enemy_x), units::Game(enemy_y)) = enemy;
+					let (units::Game(x), units::Game(y)) = origin;
+					let (dx, dy) = (enemy_x - x, enemy_y - y);
+					// in reach and not behind the player
+					if (dx * dx + dy * dy).sqrt() <= range + half_tile && dx * aim_x + dy * aim_y >= 0.0 {
+						let damage = health::Damage::new(damage, health::DamageType::Melee)
+							.knockback_from(origin, enemy, BAT_KNOCKBACK);
+						if !self.damage_zombie(i, damage) {
+							self.enemies.get_mut(i).unwrap().get_effects_mut().apply(status::Effect::Stunned, STUN_FRAMES);
+						}
+						connected = true;
+					}
+				}
+				if connected {
+					self.music.play_sound_effect(6);
+					self.player.wear_weapon();
+					self.timer += 100;
+				}
+			},
+			None => {}
+		}
+	}
+
+	/// Drops the last part picked up at the player's feet. It can't be picked
+	/// back up straight away.
+	fn drop_part(&mut self) {
+		if let Some(mut part) = self.player.drop_part() {
+			part.set_position(self.player.character.map_x, self.player.character.map_y);
+			self.parts.push(part);
+			self.part_pickup_timer = PART_DROP_FRAMES;
+		}
+	}
+
+	/// Fires a bullet from `origin` along `direction`.
+	fn fire_bullet(&mut self, origin: (units::Game, units::Game), direction: (f64, f64), owner: projectile::Owner) {
+		let (x, y) = origin;
+		let bullet = projectile::Projectile::new(&mut self.display, x, y, projectile::Shot {
+			direction,
+			speed:     BULLET_SPEED,
+			damage:    health::Damage::new(BULLET_DAMAGE, health::DamageType::Bullet),
+			owner,
+			lifetime:  BULLET_LIFETIME,
+			frame:     projectile::BULLET_FRAME
+		});
+		self.projectiles.push(bullet);
+	}
+
+	/// Lets zombies that spit fire at the nearest player, as long as they
+	/// are close enough and can see them.
+	fn zombies_spit(&mut self) {
+		let units::Game(range) = SPIT_RANGE.to_game();
+		let mut shots: Vec<((units::Game, units::Game), (f64, f64))> = Vec::new();
+		for enemy in self.enemies.iter_mut() {
+			let origin = (enemy.get_map_x(), enemy.get_map_y());
+			let (x, y) = origin;
+			let target = Game::nearest_player(&self.player, &self.partner, x, y);
+			if target.is_down() || target.is_hidden() || target.character.distance(x, y) > range {
+				continue;
+			}
+			let aim = (target.character.map_center_x(), target.character.map_center_y());
+			if self.map.line_of_sight(origin, aim) && enemy.ready_to_spit() {
+				shots.push((origin, projectile::Projectile::direction_to(origin, aim)));
+			}
+		}
+		for ((x, y), direction) in shots {
+			let spit = projectile::Projectile::new(&mut self.display, x, y, projectile::Shot {
+				direction,
+				speed:     SPIT_SPEED,
+				damage:    health::Damage::new(SPIT_DAMAGE, health::DamageType::Contact),
+				owner:     projectile::Owner::Zombie,
+				lifetime:  SPIT_LIFETIME,
+				frame:     projectile::SPIT_FRAME
+			});
+			self.projectiles.push(spit);
+		}
+	}
+
+	/// Moves every projectile and lets player shots damage the zombies they
+	/// hit. Zombie shots are checked against each player in
+	/// `resolve_player_hits`.
+	fn update_projectiles(&mut self, elapsed_time: units::Millis) {
+		for i in 0.. self.projectiles.len() {
+			self.projectiles.get_mut(i).unwrap().update(elapsed_time, &self.map);
+			if self.projectiles.get(i).unwrap().is_finished() {
+				continue;
+			}
+
+			let rectangle = self.projectiles.get(i).unwrap().damage_rectangle();
+			match self.projectiles.get(i).unwrap().get_owner() {
+				projectile::Owner::Player => {
+					let mut target: Option<usize> = None;
+					for j in 0.. self.enemies.len() {
+						if self.enemies.get(j).unwrap().damage_rectangle().collides_with(&rectangle) {
+							target = Some(j);
+							break;
+						}
+					}
+					if let Some(j) = target {
+						let damage = self.projectiles.get_mut(i).unwrap().hit();
+						self.music.play_sound_effect(6);
+						self.damage_zombie(j, damage);
+					}
+				},
+				projectile::Owner::Zombie => {}
+			}
+		}
+		self.projectiles.retain(|projectile| !projectile.is_finished());
+	}
+
+	/// Distance from the player to whatever they should be heading for next:
+	/// the vehicle when carrying a part, otherwise the closest part.
+	fn objective_distance(&self) -> f64 {
+		if !self.player.get_parts().is_empty() {
+			return self.player.character.distance(self.vehicle.get_map_x(), self.vehicle.get_map_y());
+		}
+		let mut closest = f64::MAX;
+		for part in self.parts.iter() {
+			let distance = self.player.character.distance(part.get_map_x(), part.get_map_y());
+			if distance < closest {
+				closest = distance;
+			}
+		}
+		closest
+	}
+
+	/// The middle of the vehicle, where parts are handed over.
+	fn vehicle_center(&self) -> (units::Game, units::Game) {
+		let rectangle = self.vehicle.damage_rectangle();
+		(rectangle.left() + (rectangle.width() / units::Game(2.0)), rectangle.top() + (rectangle.height() / units::Game(2.0)))
+	}
+
+	/// Where zombies near the vehicle should head, if anywhere. They only
+	/// bother once there is something to strip and neither player is up and
+	/// around to guard it.
+	fn vehicle_target(&self) -> Option<(units::Game, units::Game)> {
+		if self.vehicle.installed_parts() == 0 || self.vehicle.is_boarded() {
+			return None;
+		}
+		let (x, y) = self.vehicle_center();
+		let units::Game(guard_radius) = VEHICLE_GUARD_RADIUS.to_game();
+		let guards = |player: &player::Player| !player.is_down() && player.character.distance(x, y) <= guard_radius;
+		let guarded = match self.partner {
+			Some(ref partner) => guards(&self.player) || guards(partner),
+			None              => guards(&self.player)
+		};
+		if guarded {
+			None
+		} else {
+			Some((x, y))
+		}
+	}
+
+	/// Zombies touching the vehicle damage it, and enough damage knocks the
+	/// last part installed back off.
+	fn attack_vehicle(&mut self) {
+		if self.vehicle.installed_parts() == 0 || self.vehicle.is_boarded() {
+			return;
+		}
+		let vehicle_rectangle = self.vehicle.damage_rectangle();
+		let attacked = self.enemies.iter().any(|enemy| enemy.damage_rectangle().collides_with(&vehicle_rectangle));
+		if !attacked {
+			return;
+		}
+		let result = self.vehicle.take_damage(health::Damage::new(VEHICLE_DAMAGE, health::DamageType::Contact));
+		if result != health::DamageResult::Killed {
+			return;
+		}
+		if let Some(part_type) = self.vehicle.knock_part_loose() {
+			println!("PART KNOCKED LOOSE");
+			self.music.play_sound_effect(6);
+			self.scatter_part(part_type - 1);
+		}
+	}
+
+	/// Throws part `kind` down somewhere near the vehicle.
+	fn scatter_part(&mut self, kind: u32) {
+		let mut rng = rand::thread_rng();
+		let (center_x, center_y) = self.vehicle_center();
+		let units::Tile(tile_x) = center_x.to_tile();
+		let units::Tile(tile_y) = center_y.to_tile();
+		let spread = LOOSE_PART_SPREAD as i32;
+		let (_, max) = POSSIBLE_PART_RANGE;
+		let last_tile = max as i32 - 1;
+		let x = cmp::min(cmp::max(tile_x as i32 + rng.gen_range(-spread..spread + 1), 1), last_tile);
+		let y = cmp::min(cmp::max(tile_y as i32 + rng.gen_range(-spread..spread + 1), 1), last_tile);
+		let part = self.vehicle.make_part(
+			&mut self.display,
+			kind,
+			units::Tile(x as u32).to_game(),
+			units::Tile(y as u32).to_game()
+		);
+		let colliding_tiles = self.map.get_colliding_tiles(&part.damage_rectangle());
+		for tile in colliding_tiles.iter() {
+			if tile.tile_type == map::TileType::Wall {
+				self.scatter_part(kind);
+				return;
+			}
+		}
+		self.parts.push(part);
+	}
+
+	/// Hands the compass whatever the player should be heading for.
+	fn update_compass(&mut self) {
+		self.compass.clear();
+		let (vehicle_x, vehicle_y) = self.vehicle_center();
+		if self.vehicle.is_boarded() {
+			self.compass.add(compass::Objective::Extraction, vehicle::EXTRACTION_X.to_game(), vehicle_y);
+			return;
+		}
+
+		// the vehicle once anyone is carrying a part, parts while anyone has room
+		let mut carrying = !self.player.get_parts().is_empty();
+		let mut room = self.player.get_parts().len() < MAX_CARRIED_PARTS;
+		if let Some(ref partner) = self.partner {
+			carrying = carrying || !partner.get_parts().is_empty();
+			room = room || partner.get_parts().len() < MAX_CARRIED_PARTS;
+		}
+		if carrying {
+			self.compass.add(compass::Objective::Vehicle, vehicle_x, vehicle_y);
+		}
+		if room {
+			for part in self.parts.iter() {
+				self.compass.add(compass::Objective::Part, part.get_map_x(), part.get_map_y());
 			}
 		}
+		if self.vehicle_target().is_some() && self.vehicle.get_health() < self.vehicle.get_max_health() {
+			self.compass.add(compass::Objective::VehicleUnderAttack, vehicle_x, vehicle_y);
+		}
+		for powerup in self.powerups.iter().filter(|powerup| !powerup.is_debuff()) {
+			self.compass.add(compass::Objective::Powerup, powerup.get_map_x(), powerup.get_map_y());
+		}
 	}
 
-	fn update_cinematic(&mut self, elapsed_time: units::Millis) {
+	/// Drives the finished vehicle towards the extraction edge. The player
+	/// rides along, so zombies keep chasing the vehicle.
+	fn update_escape(&mut self, elapsed_time: units::Millis) {
 		self.map.update();
 		for i in 0u32.. self.enemies.len() as u32 { 
 			let enemy = self.enemies.get_mut(i as usize).unwrap();
-			let (player_x, player_y) = self.player.get_follow_coords();
-			enemy.set_acceleration(player_x, player_y); 
+			let scent = self.player.track_scent(&self.map, enemy.get_map_x() + units::HalfTile(1).to_game(), enemy.get_map_y() + units::HalfTile(1).to_game());
+			enemy.set_acceleration(scent); 
 			enemy.update(elapsed_time, &self.map); 
 		}
+		for i in 0.. self.killed.len() { self.killed.get_mut(i).unwrap().update(elapsed_time, &self.map) }
 		self.vehicle.update(elapsed_time);
+
+		let mut crashed = self.vehicle.drive(elapsed_time, &self.map);
+		let vehicle_rectangle = self.vehicle.damage_rectangle();
+		let (center_x, center_y) = self.vehicle_center();
+		self.player.character.map_x = center_x - units::HalfTile(1);
+		self.player.character.map_y = center_y - units::HalfTile(1);
+		if let Some(ref mut partner) = self.partner {
+			partner.character.map_x = center_x - units::HalfTile(1);
+			partner.character.map_y = center_y - units::HalfTile(1);
+		}
+		self.map.set_page(center_x, center_y);
+
+		// the car knocks zombies flying, the helicopter has to dodge them
+		let mut i = 0;
+		while i < self.enemies.len() {
+			if self.enemies[i].damage_rectangle().collides_with(&vehicle_rectangle) {
+				if self.vehicle.rams_zombies() {
+					self.make_noise(center_x, center_y, noise::GUNFIRE);
+					if self.damage_zombie(i, health::Damage::new(RAM_DAMAGE, health::DamageType::Contact)) {
+						continue;
+					}
+				} else if self.vehicle.is_vulnerable() {
+					crashed = self.vehicle.crash() || crashed;
+				}
+			}
+			i += 1;
+		}
+
+		self.update_compass();
+
+		if crashed {
+			println!("CRASH");
+			self.music.play_sound_effect(6);
+			self.score = cmp::max(0, self.score - CRASH_PENALTY);
+		}
+
+		if self.vehicle.has_escaped() {
+			self.set_score_and_timer();
+			if self.mode == mode::Mode::Campaign {
+				self.unlock_next_level();
+			}
+			self.completed_lvl = true;
+		}
+
+		// ran out of time
+		if self.timer <= 0 {
+			self.game_over();
+		}
+	}
+
+	/// Picks up the powerup at `index`. Auto-triggering powerups go off straight
+	/// away, anything else is stored if there is room and left lying otherwise.
+	fn pick_up_powerup(&mut self, index: u32) {
+		if self.powerups.get(index as usize).unwrap().is_debuff() || self.powerups.get(index as usize).unwrap().auto_trigger() {
+			let powerup = self.powerups.remove(index as usize);
+			self.apply_powerup(powerup);
+		} else if !self.inventory.is_full() {
+			let powerup = self.powerups.remove(index as usize);
+			self.music.play_sound_effect(3);
+			let _ = self.inventory.add(powerup);
+		}
+	}
+
+	/// Uses whatever is stored in inventory `slot`.
+	fn use_powerup(&mut self, slot: usize) {
+		if let Some(powerup) = self.inventory.take(slot) {
+			self.apply_powerup(powerup);
+		}
+	}
+
+	/// -1, 0 or 1 depending on which of two opposing keys is held.
+	fn key_direction(negative: bool, positive: bool) -> i32 {
+		match (negative, positive) {
+			(true, false) => -1,
+			(false, true) => 1,
+			_             => 0
+		}
 	}
 
-	fn apply_powerup(&mut self, index: u32) {
-		let powerup = self.powerups.remove(index as usize);
+	fn slot_key(slot: usize) -> Option<Keycode> {
+		match slot {
+			0 => Some(Keycode::Num1),
+			1 => Some(Keycode::Num2),
+			2 => Some(Keycode::Num3),
+			3 => Some(Keycode::Num4),
+			4 => Some(Keycode::Num5),
+			5 => Some(Keycode::Num6),
+			6 => Some(Keycode::Num7),
+			7 => Some(Keycode::Num8),
+			8 => Some(Keycode::Num9),
+			_ => None
+		}
+	}
+
+	/// The second player's inventory keys, the three after player one's.
+	fn partner_slot_key(slot: usize) -> Option<Keycode> {
+		match slot {
+			0 => Some(Keycode::Num4),
+			1 => Some(Keycode::Num5),
+			2 => Some(Keycode::Num6),
+			_ => None
+		}
+	}
+
+	fn apply_powerup(&mut self, powerup: Box<dyn powerups::Powerup>) {
+		if powerup.is_debuff() {
+			self.apply_debuff();
+			return;
+		}
 		let kind = powerup.get_type();
-		let length = self.enemies.len();
 		match kind {
 			// kill next zombie you touch without dying
 			1 => { 
 				println!("CRICKET BAT"); 
 				self.music.play_sound_effect(3); 
-				self.player.give_bat(); 
+				let bat = weapons::Weapon::new(&mut self.display, weapons::CRICKET_BAT);
+				self.player.give_weapon(bat);
 			},
-			// kill random zombie
+			// shoot the nearest zombie
 			2 => { 
 				println!("KILL ZOMBIE"); 
-				if self.enemies.len() > 0 {
-					self.music.play_sound_effect(0);
-					let mut rng = rand::thread_rng(); 
-					let killed = self.enemies.remove( rng.gen_range(0u32..length as u32) as usize );
-					let mut mut_enemy = killed;
-					self.activated.push(powerup);
-					mut_enemy.kill_zombie();
-					self.killed.push(mut_enemy);
+				self.music.play_sound_effect(0);
+				let origin = (self.player.character.map_center_x(), self.player.character.map_center_y());
+				let (player_x, player_y) = origin;
+				self.make_noise(player_x, player_y, noise::GUNFIRE);
+				let mut direction = self.player.aim_direction();
+				let mut closest = f64::MAX;
+				for enemy in self.enemies.iter() {
+					let target = (enemy.get_map_x() + units::HalfTile(1).to_game(), enemy.get_map_y() + units::HalfTile(1).to_game());
+					let (target_x, target_y) = target;
+					let distance = self.player.character.distance(target_x, target_y);
+					if distance < closest {
+						closest = distance;
+						direction = projectile::Projectile::direction_to(origin, target);
+					}
 				}
+				self.fire_bullet(origin, direction, projectile::Owner::Player);
 				self.timer = self.timer + 100;
 			},
 			// wipe out all zombies in given range
 			3 => { 
 				println!("WIPE OUT");
 				self.music.play_sound_effect(1); 
-				let mut new_enemies: Vec<Box<dyn enemies::Zombie>> = Vec::new();
-				for _ in 0.. self.enemies.len() { 
-					let enemy = self.enemies.pop();
-					match enemy {
-						Some(enemy) => {
-							if self.player.character.distance( enemy.get_map_x(), enemy.get_map_y() ) > 200.0 {
-						    	new_enemies.push(enemy);
-						    } else {
-						    	let mut mut_enemy = enemy;
-						    	mut_enemy.kill_zombie();
-						    	self.killed.push(mut_enemy);
-						    }
-						},
-						None => {}
+				let center = (self.player.character.map_center_x(), self.player.character.map_center_y());
+				let (center_x, center_y) = center;
+				self.make_noise(center_x, center_y, noise::EXPLOSION);
+				for i in (0.. self.enemies.len()).rev() { 
+					let enemy = (self.enemies.get(i).unwrap().get_map_x(), self.enemies.get(i).unwrap().get_map_y());
+					let (enemy_x, enemy_y) = enemy;
+					if self.player.character.distance( enemy_x, enemy_y ) <= 200.0 {
+						let damage = health::Damage::new(EXPLOSION_DAMAGE, health::DamageType::Explosion)
+							.knockback_from(center, enemy, BAT_KNOCKBACK);
+						self.damage_zombie(i, damage);
 					}
 				}
-				self.timer = self.timer + (self.killed.len() as i32 * 500);
-				self.enemies = new_enemies;
+				self.timer += self.killed.len() as i32 * 500;
 				let mut mut_powerup = powerup;
 				mut_powerup.set_timer();
 				self.activated.push(mut_powerup);
@@ -1000,7 +2356,9 @@ impl<'e> Game<'e> {
 			4 => { 
 				println!("FREEZE"); 
 				self.music.play_sound_effect(3);
-				self.freeze_counter = 300; 
+				for enemy in self.enemies.iter_mut() {
+					enemy.get_effects_mut().apply(status::Effect::Frozen, FREEZE_FRAMES);
+				}
 			},
 			5 => { 
 				println!("TELEPORT"); 
@@ -1009,76 +2367,385 @@ impl<'e> Game<'e> {
 				self.player.character.map_x = self.vehicle.get_map_x(); 
 				self.player.character.map_y = self.vehicle.get_map_y() + units::Tile(2).to_game(); 
 				self.player.start_teleport_timer();
+				self.player.break_trail();
 				let mut mut_powerup = powerup;
 				mut_powerup.set_timer();
 				self.activated.push(mut_powerup);
 			},
+			// refill stamina
+			7 => {
+				println!("ENERGY DRINK");
+				self.music.play_sound_effect(3);
+				self.player.refill_stamina();
+			},
+			// sprint for free for a while
+			8 => {
+				println!("ADRENALINE");
+				self.music.play_sound_effect(3);
+				self.player.boost_stamina();
+			},
+			// heal
+			11 => {
+				println!("HEART");
+				self.music.play_sound_effect(3);
+				self.player.heal(HEART_HEAL);
+			},
+			// dropped by zombies
+			14 => {
+				println!("AMMO");
+				self.music.play_sound_effect(3);
+				self.player.add_ammo(AMMO_DROP);
+			},
+			15 => {
+				println!("TIME BONUS");
+				self.music.play_sound_effect(3);
+				self.timer += TIME_BONUS;
+			},
+			// a trap or barricade to put down later
+			13 => {
+				println!("TRAP KIT");
+				self.music.play_sound_effect(3);
+				let kind = rand::thread_rng().gen_range(0..TRAP_KINDS);
+				self.trap_kits[kind] += 1;
+				if self.trap_kits[self.selected_trap] == 0 {
+					self.selected_trap = kind;
+				}
+			},
+			// raise max health for the rest of the run
+			12 => {
+				println!("HEART CONTAINER");
+				self.music.play_sound_effect(7);
+				self.player.raise_max_health(HEART_CONTAINER_HP);
+			},
+			// arm the player
+			9 | 10 => {
+				println!("NEW WEAPON");
+				self.music.play_sound_effect(3);
+				let weapon = weapons::Weapon::new(&mut self.display, if kind == 9 { weapons::PISTOL } else { weapons::MACHINE_GUN });
+				self.player.give_weapon(weapon);
+			},
 			_ => { 
-				if powerup.is_debuff() {
-					println!("SUCKS TO BE YOU"); 
-					self.music.play_sound_effect(4);
-					let mut rng = rand::thread_rng();
-					let mut new_enemies: Vec<Box<dyn enemies::Zombie>> = Vec::new();
-					for _ in 0.. self.enemies.len() { 
-						let enemy = self.enemies.pop();
-						match enemy {
-							Some(enemy) => {
-								if rng.gen_range(1u32..11u32) >= 3 {
-									let crazy_zombie = Box::new( enemies::CrazyZombie::new(
-										&mut self.display, 
-										enemy.get_map_x(),
-										enemy.get_map_y()
-									) );
-							    	new_enemies.push(crazy_zombie);
-							    } else {
-							    	new_enemies.push(enemy);
-							    }
-							},
-							None => {}
-						}
-					}
-					self.enemies = new_enemies;
-				} else {
-					println!("NUKE"); 
-					self.music.play_sound_effect(2);
-					let mut new_enemies: Vec<Box<dyn enemies::Zombie>> = Vec::new();
-					for _ in 0.. self.enemies.len() {
-						match self.enemies.pop() {
-							Some(enemy) => {
-								if self.map.on_screen(enemy.get_map_x(), enemy.get_map_y()) {
-									let mut mut_enemy = enemy;
-									mut_enemy.kill_zombie();
-									self.killed.push(mut_enemy);
-								} else {
-									new_enemies.push(enemy);
-								}
-							},
-							None => {}
-						};
+				println!("NUKE"); 
+				self.music.play_sound_effect(2);
+				let (player_x, player_y) = (self.player.character.map_center_x(), self.player.character.map_center_y());
+				self.make_noise(player_x, player_y, noise::EXPLOSION);
+				for i in (0.. self.enemies.len()).rev() {
+					let (enemy_x, enemy_y) = (self.enemies.get(i).unwrap().get_map_x(), self.enemies.get(i).unwrap().get_map_y());
+					if self.map.on_screen(enemy_x, enemy_y) {
+						self.damage_zombie(i, health::Damage::new(EXPLOSION_DAMAGE, health::DamageType::Explosion));
 					}
-					let mut mut_powerup = powerup;
-					mut_powerup.set_timer();
-					self.activated.push(mut_powerup);
-					self.timer = self.timer + (self.killed.len() as i32 * 100);
-					self.enemies = new_enemies;
-				} 
+				}
+				let mut mut_powerup = powerup;
+				mut_powerup.set_timer();
+				self.activated.push(mut_powerup);
+				self.timer += self.killed.len() as i32 * 100;
 			}
 		};
 	}
 
-	fn activate_trap(&mut self, index: u32) {
-		let trap = self.traps.remove(index as usize);
-		let kind = trap.get_type();
-		match kind {
+	/// Rerolls which powerups lying around are cursed, by each kind's odds.
+	fn roll_debuffs(&mut self) {
+		let mut rng = rand::thread_rng();
+		for powerup in self.powerups.iter_mut() {
+			let odds = powerups::debuff_odds(&self.debuff_odds, powerup.get_type());
+			powerup.set_debuff(rng.gen_range(0u32..100u32) < odds);
+		}
+	}
+
+	/// Outlines cursed powerups in red. Shortly before curses are rerolled,
+	/// every powerup that could turn bad flashes yellow.
+	fn draw_debuff_warnings(&mut self) {
+		let rerolling = DEBUFF_ROLL_INTERVAL - (self.updates % DEBUFF_ROLL_INTERVAL) <= DEBUFF_WARNING_FRAMES;
+		let flash = (self.updates / 8) % 2 == 0;
+		let units::Game(size) = units::Tile(1).to_game();
+		for powerup in self.powerups.iter() {
+			if !self.map.on_screen(powerup.get_map_x(), powerup.get_map_y()) {
+				continue;
+			}
+			let color = if rerolling && powerups::debuff_odds(&self.debuff_odds, powerup.get_type()) > 0 {
+				if flash { Some((240, 200, 40)) } else { None }
+			} else if powerup.is_debuff() {
+				Some((200, 40, 40))
+			} else {
+				None
+			};
+			if let Some(color) = color {
+				let rectangle = powerup.damage_rectangle();
+				let units::Game(x) = rectangle.left() % common::SCREEN_CORRECTION;
+				let units::Game(y) = rectangle.top() % common::SCREEN_CORRECTION;
+				self.display.draw_box(rect!(x, y, size, size), color);
+			}
+		}
+	}
+
+	/// Springs a random curse on the player for picking up a cursed powerup.
+	fn apply_debuff(&mut self) {
+		self.music.play_sound_effect(4);
+		let mut rng = rand::thread_rng();
+		match rng.gen_range(0u32..DEBUFF_KINDS) {
+			// most zombies are whipped into a frenzy
+			0 => {
+				println!("SUCKS TO BE YOU"); 
+				for enemy in self.enemies.iter_mut() {
+					if rng.gen_range(1u32..11u32) >= 3 {
+						enemy.get_effects_mut().apply(status::Effect::Haste, ENRAGE_FRAMES);
+					}
+				}
+			},
+			1 => {
+				println!("REVERSED CONTROLS");
+				self.player.character.effects.apply(status::Effect::Confused, REVERSE_FRAMES);
+			},
+			2 => {
+				println!("DARKNESS");
+				self.player.character.effects.apply(status::Effect::Blinded, DARKNESS_FRAMES);
+			},
+			3 => {
+				println!("SLOWNESS");
+				self.player.character.effects.apply(status::Effect::Slow, DEBUFF_SLOW_FRAMES);
+			},
+			4 => {
+				println!("ZOMBIE MAGNET");
+				self.player.character.effects.apply(status::Effect::Magnetized, MAGNET_FRAMES);
+			},
+			_ => {
+				println!("HORDE");
+				for _ in 0.. HORDE_SIZE {
+					let location = self.director.spawn_location(&mut self.level_rng, &self.map);
+					self.spawn_zombie(rng.gen_range(1u32..4u32), location);
+				}
+			}
+		}
+	}
+
+	/// Sets off the trap at `index` on the zombie at `victim`, or on the player
+	/// when there is no victim. Returns whether the player should be hurt.
+	fn activate_trap(&mut self, index: u32, victim: Option<usize>) -> bool {
+		let index = index as usize;
+		let kind = self.traps.get(index).unwrap().get_type();
+		let (trap_x, trap_y) = (self.traps.get(index).unwrap().get_map_x(), self.traps.get(index).unwrap().get_map_y());
+		let spent = self.traps.get_mut(index).unwrap().trigger();
+		let hurts_player = match kind {
+			// Activate spikes
+			traps::SPIKES => {
+				println!("SPIKES");
+				self.music.play_sound_effect(6);
+				if let Some(i) = victim {
+					if !self.damage_zombie(i, health::Damage::new(SPIKE_DAMAGE, health::DamageType::Trap)) {
+						self.enemies.get_mut(i).unwrap().get_effects_mut().apply(status::Effect::Slow, SLOW_FRAMES);
+					}
+				}
+				victim.is_none()
+			},
+			// Light landmine fuse, the blast is dealt with once it goes off
+			traps::LANDMINE => {
+				println!("LANDMINE");
+				self.music.play_sound_effect(5);
+				false
+			},
+			// Alert every zombie within earshot
+			traps::TRIPWIRE => {
+				println!("TRIPWIRE");
+				self.music.play_sound_effect(4);
+				self.make_noise(trap_x, trap_y, noise::TRIPWIRE);
+				false
+			},
+			// Drop zombie into pit
+			traps::PIT => {
+				println!("PIT");
+				self.music.play_sound_effect(5);
+				if let Some(i) = victim {
+					let zombie = self.enemies.remove(i);
+					self.traps.get_mut(index).unwrap().hold(zombie);
+				}
+				false
+			},
+			// Fling player
+			traps::SPRING => {
+				println!("SPRING");
+				self.music.play_sound_effect(3);
+				self.player.fling();
+				false
+			},
 			// Activate bear trap
 			_ => { 
 				println!("BEAR TRAP");
 				self.music.play_sound_effect(5);
-				let mut mut_trap = trap;
-				mut_trap.set_timer();
-				self.tripped.push(mut_trap);
+				self.make_noise(trap_x, trap_y, noise::TRAP_SNAP);
+				if let Some(i) = victim {
+					self.damage_zombie(i, health::Damage::new(TRAP_DAMAGE, health::DamageType::Trap));
+				}
+				victim.is_none()
 			}
 		};
+		if spent {
+			let trap = self.traps.remove(index);
+			self.tripped.push(trap);
+		}
+		hurts_player
+	}
+
+	/// Sets off every landmine whose fuse has burnt down, hurting every zombie
+	/// caught in the blast. Returns where each blast went off so the players
+	/// caught in it can be hurt alongside their other hits this update.
+	fn detonate_landmines(&mut self) -> Vec<(units::Game, units::Game)> {
+		let mut blasts = Vec::new();
+		let units::Game(radius) = LANDMINE_RADIUS.to_game();
+		for t in (0.. self.traps.len()).rev() {
+			if !self.traps.get(t).unwrap().is_detonating() {
+				continue;
+			}
+			let trap = self.traps.remove(t);
+			let center = (trap.get_map_x(), trap.get_map_y());
+			let (center_x, center_y) = center;
+			println!("BOOM");
+			self.music.play_sound_effect(2);
+			self.make_noise(center_x, center_y, noise::EXPLOSION);
+			for i in (0.. self.enemies.len()).rev() {
+				let enemy = (self.enemies.get(i).unwrap().get_map_x() + units::HalfTile(1).to_game(),
+				             self.enemies.get(i).unwrap().get_map_y() + units::HalfTile(1).to_game());
+				let (units::Game(enemy_x), units::Game(enemy_y)) = enemy;
+				let (units::Game(x), units::Game(y)) = center;
+				if ((enemy_x - x) * (enemy_x - x) + (enemy_y - y) * (enemy_y - y)).sqrt() <= radius {
+					let damage = health::Damage::new(LANDMINE_DAMAGE, health::DamageType::Explosion)
+						.knockback_from(center, enemy, BAT_KNOCKBACK);
+					if !self.damage_zombie(i, damage) {
+						self.enemies.get_mut(i).unwrap().get_effects_mut().apply(status::Effect::Burning, BURN_FRAMES);
+					}
+				}
+			}
+			blasts.push(center);
+		}
+		blasts
+	}
+
+	/// Counts down the status effects on `self.player`, handing back any burn
+	/// so it is dealt with alongside their other hits this update.
+	fn update_player_effects(&mut self) -> Option<health::Damage> {
+		let mut burn: Option<health::Damage> = None;
+		for event in self.player.character.effects.update() {
+			if let status::Event::Tick(status::Effect::Burning, stacks) = event {
+				burn = Some(health::Damage::new(BURN_DAMAGE * stacks, health::DamageType::Fire));
+			}
+		}
+		burn
+	}
+
+	/// Counts down every status effect on the zombies, burning ones are hurt
+	/// straight away.
+	fn update_zombie_effects(&mut self) {
+		for i in (0.. self.enemies.len()).rev() {
+			for event in self.enemies.get_mut(i).unwrap().get_effects_mut().update() {
+				if let status::Event::Tick(status::Effect::Burning, stacks) = event {
+					self.damage_zombie(i, health::Damage::new(BURN_DAMAGE * stacks, health::DamageType::Fire));
+					break;
+				}
+			}
+		}
+	}
+
+	/// Lets zombies climb back out of pits once they have been held long enough.
+	fn release_zombies(&mut self) {
+		for trap in self.traps.iter_mut() {
+			if let Some(zombie) = trap.release() {
+				self.enemies.push(zombie);
+			}
+		}
+	}
+
+	fn trap_name(kind: i32) -> &'static str {
+		match kind {
+			traps::BEAR_TRAP => "BEAR TRAP",
+			traps::SPIKES    => "SPIKES",
+			_                => "BARRICADE"
+		}
+	}
+
+	/// Selects the next kind of trap kit the player is carrying.
+	fn select_next_trap(&mut self) {
+		for offset in 1.. TRAP_KINDS + 1 {
+			let kind = (self.selected_trap + offset) % TRAP_KINDS;
+			if self.trap_kits[kind] > 0 {
+				self.selected_trap = kind;
+				return;
+			}
+		}
+	}
+
+	/// The tile under the player's feet, where a trap would be placed.
+	fn trap_placement(&self) -> (units::Game, units::Game) {
+		let (col, row) = (self.player.character.map_center_x().to_tile(), self.player.character.map_center_y().to_tile());
+		(col.to_game(), row.to_game())
+	}
+
+	/// Whether the selected trap can go down: the player has a kit, hasn't hit
+	/// the level's limit and there isn't a trap on the tile already.
+	fn can_place_trap(&self) -> bool {
+		if self.trap_kits[self.selected_trap] == 0 || self.traps_placed >= MAX_PLACED_TRAPS {
+			return false;
+		}
+		let (x, y) = self.trap_placement();
+		let (col, row) = (x.to_tile(), y.to_tile());
+		!self.traps.iter().any(|trap| trap.get_map_x().to_tile() == col && trap.get_map_y().to_tile() == row)
+	}
+
+	/// Puts the selected trap down at the player's feet.
+	fn place_trap(&mut self) {
+		if !self.can_place_trap() {
+			return;
+		}
+		let kind = self.selected_trap;
+		self.trap_kits[kind] -= 1;
+		let (x, y) = self.trap_placement();
+		let mut trap = self.create_trap(kind as i32 + 1, x, y);
+		trap.set_placed();
+		self.traps.push(trap);
+		self.traps_placed += 1;
+		self.music.play_sound_effect(5);
+		if self.trap_kits[kind] == 0 {
+			self.select_next_trap();
+		}
+	}
+
+	/// Outlines the tile the selected trap would go on, green if it can be
+	/// placed there and red if not.
+	fn draw_trap_preview(&mut self) {
+		if self.trap_kits[self.selected_trap] == 0 {
+			return;
+		}
+		let (x, y) = self.trap_placement();
+		let units::Game(screen_x) = x % common::SCREEN_CORRECTION;
+		let units::Game(screen_y) = y % common::SCREEN_CORRECTION;
+		let units::Game(size) = units::Tile(1).to_game();
+		let color = if self.can_place_trap() { (40, 200, 40) } else { (200, 40, 40) };
+		self.display.draw_box(rect!(screen_x, screen_y, size, size), color);
+	}
+
+	/// Holds zombies back at barricades. Every zombie pushing on one batters
+	/// it, and it falls once it runs out of health.
+	fn update_barricades(&mut self) {
+		for i in (0.. self.traps.len()).rev() {
+			if !self.traps.get(i).unwrap().blocks_zombies() {
+				continue;
+			}
+			let rectangle = self.traps.get(i).unwrap().damage_rectangle();
+			let mut broken = false;
+			for enemy in self.enemies.iter_mut() {
+				if enemy.damage_rectangle().collides_with(&rectangle) {
+					enemy.push_out_of(&rectangle);
+					let damage = health::Damage::new(CONTACT_DAMAGE, health::DamageType::Contact);
+					if self.traps.get_mut(i).unwrap().take_damage(damage) == health::DamageResult::Killed {
+						broken = true;
+					}
+				}
+			}
+			if broken {
+				println!("BARRICADE BROKEN");
+				let trap = self.traps.remove(i);
+				self.music.play_sound_effect(5);
+				self.make_noise(trap.get_map_x(), trap.get_map_y(), noise::TRAP_SNAP);
+			}
+		}
 	}
 
 	fn get_highscore() -> i32 {
@@ -14,7 +14,9 @@ use sdl2::ttf::Sdl2TtfContext;
 use sdl2::video::{Window, WindowContext};
 use sdl2::pixels;
 
-static MAX_HP: u32 = 3;
+// health display, in pixels
+static HEART_SIZE:   u32 = 25;
+static HEALTH_WIDTH: u32 = 175;
 
 // fail when error
 macro_rules! trying(
@@ -145,15 +147,97 @@ impl Graphics {
 		self.screen.draw_line(source_point, dest_point);
 	}
 
+	/// Draws a meter filled to `value` out of `max` inside `dest_rect`.
 	#[allow(unused_must_use)]
-	pub fn draw_health(&mut self, hp: u32) {
+	pub fn draw_bar(&mut self, value: f64, max: f64, dest_rect: rect::Rect, color: (u8, u8, u8)) {
+		let (r, g, b) = color;
+		let fill = if max > 0.0 { (value / max).clamp(0.0, 1.0) } else { 0.0 };
+		let filled = rect::Rect::new(dest_rect.x(), dest_rect.y(), (dest_rect.width() as f64 * fill) as u32, dest_rect.height());
+
+		self.screen.set_draw_color(pixels::Color::RGB(40, 40, 40));
+		self.screen.fill_rect(dest_rect);
+		if filled.width() > 0 {
+			self.screen.set_draw_color(pixels::Color::RGB(r, g, b));
+			self.screen.fill_rect(filled);
+		}
+		self.screen.set_draw_color(pixels::Color::RGB(0, 0, 0));
+	}
+
+	/// Draws the outline of `dest_rect`.
+	#[allow(unused_must_use)]
+	pub fn draw_box(&mut self, dest_rect: rect::Rect, color: (u8, u8, u8)) {
+		let (r, g, b) = color;
+		self.screen.set_draw_color(pixels::Color::RGB(r, g, b));
+		self.screen.draw_rect(dest_rect);
+		self.screen.set_draw_color(pixels::Color::RGB(0, 0, 0));
+	}
+
+	/// Draws a solid arrowhead `size` pixels long with its tip at `tip`,
+	/// pointing along `direction`.
+	#[allow(unused_must_use)]
+	pub fn draw_arrow(&mut self, tip: (i32, i32), direction: (f64, f64), size: u32, color: (u8, u8, u8)) {
+		let (x, y) = tip;
+		let (dx, dy) = direction;
+		let length = (dx * dx + dy * dy).sqrt();
+		if length == 0.0 {
+			return;
+		}
+		let (dx, dy) = (dx / length, dy / length);
+		let size = size as f64;
+		let (back_x, back_y) = (x as f64 - dx * size, y as f64 - dy * size);
+		let (side_x, side_y) = (-dy * size / 2.0, dx * size / 2.0);
+
+		let (r, g, b) = color;
+		self.screen.set_draw_color(pixels::Color::RGB(r, g, b));
+		let steps = size as i32 * 2;
+		for i in 0.. steps + 1 {
+			let across = (i as f64 / steps as f64) * 2.0 - 1.0;
+			let base = rect::Point::new((back_x + side_x * across) as i32, (back_y + side_y * across) as i32);
+			self.screen.draw_line(rect::Point::new(x, y), base);
+		}
+		self.screen.set_draw_color(pixels::Color::RGB(0, 0, 0));
+	}
+
+	/// Blacks out the screen apart from a square `radius` pixels either side
+	/// of `x`, `y`.
+	#[allow(unused_must_use)]
+	pub fn draw_darkness(&mut self, x: i32, y: i32, radius: u32) {
+		let (units::Pixel(w), units::Pixel(h)) = 
+			(game::game::SCREEN_WIDTH.to_pixel(), game::game::SCREEN_HEIGHT.to_pixel());
+		let (w, h, r) = (w, h, radius as i32);
+		let (left, top) = (x - r, y - r);
+		let (right, bottom) = (x + r, y + r);
+
+		self.screen.set_draw_color(pixels::Color::RGB(0, 0, 0));
+		if top > 0 {
+			self.screen.fill_rect(rect::Rect::new(0, 0, w as u32, top as u32));
+		}
+		if bottom < h {
+			self.screen.fill_rect(rect::Rect::new(0, bottom, w as u32, (h - bottom) as u32));
+		}
+		if left > 0 {
+			self.screen.fill_rect(rect::Rect::new(0, top, left as u32, (2 * r) as u32));
+		}
+		if right < w {
+			self.screen.fill_rect(rect::Rect::new(right, top, (w - right) as u32, (2 * r) as u32));
+		}
+	}
+
+	/// Draws a row of hearts, shrinking them so any max health fits in the
+	/// space set aside for the health display.
+	pub fn draw_health(&mut self, hp: u32, max_hp: u32) {
+		self.draw_health_at(hp, max_hp, 0, 0);
+	}
+
+	/// Draws a row of hearts starting at `x`, `y`.
+	pub fn draw_health_at(&mut self, hp: u32, max_hp: u32, x: u32, y: u32) {
 		let heart_sprites = "assets/base/heart.bmp"; 
 		self.load_image(String::from(heart_sprites), true);
 		let full_source = rect::Rect::new(0, 0, 18, 18);
 		let empty_source = rect::Rect::new(21, 0, 18, 18);
-		for i in 0.. MAX_HP {
-			let x = i * 25;
-			let dest = rect::Rect::new(x as i32, 0, 25, 25);
+		let size = if max_hp * HEART_SIZE > HEALTH_WIDTH { HEALTH_WIDTH / max_hp } else { HEART_SIZE };
+		for i in 0.. max_hp {
+			let dest = rect::Rect::new((x + i * size) as i32, y as i32, size, size);
 			if i < hp {
 				self.blit_surface(&heart_sprites, &full_source, &dest);
 			} else {
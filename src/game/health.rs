@@ -0,0 +1,148 @@
+use std::cmp;
+
+use game::units;
+
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum DamageType {
+	Contact,
+	Melee,
+	Bullet,
+	Explosion,
+	Trap,
+	Fire
+}
+
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum DamageResult {
+	Ignored,
+	Hurt,
+	Killed
+}
+
+/// A single hit: how much it hurts, what caused it and how hard it shoves
+/// the target.
+#[derive(Clone,Copy)]
+pub struct Damage {
+	pub amount:    u32,
+	pub kind:      DamageType,
+	pub knockback: (units::Velocity, units::Velocity)
+}
+
+impl Damage {
+	pub fn new(amount: u32, kind: DamageType) -> Damage {
+		Damage {
+			amount,
+			kind,
+			knockback: (units::Velocity(0.0), units::Velocity(0.0))
+		}
+	}
+
+	/// Knocks the target directly away from `source` at `strength`.
+	pub fn knockback_from(self,
+	                      source: (units::Game, units::Game),
+	                      target: (units::Game, units::Game),
+	                      strength: units::Velocity) -> Damage {
+		let ((units::Game(sx), units::Game(sy)), (units::Game(tx), units::Game(ty))) = (source, target);
+		let units::Velocity(speed) = strength;
+		let (dx, dy) = (tx - sx, ty - sy);
+		let length = (dx * dx + dy * dy).sqrt();
+		if length == 0.0 {
+			return self;
+		}
+
+		let mut damage = self;
+		damage.knockback = (units::Velocity(speed * dx / length), units::Velocity(speed * dy / length));
+		damage
+	}
+}
+
+/// Hit points shared by anything that can be hurt.
+///
+/// After taking damage the owner is invulnerable for `invulnerable_frames`
+/// updates. `take_damage` reports `Killed` exactly once, on the hit that
+/// empties the pool, so owners can run their death callback from there.
+pub struct Health {
+	current:             u32,
+	max:                 u32,
+	invulnerable_frames: i32,
+	invulnerable_timer:  i32
+}
+
+impl Health {
+	pub fn new(max: u32, invulnerable_frames: i32) -> Health {
+		Health {
+			current:             max,
+			max,
+			invulnerable_frames,
+			invulnerable_timer:  0
+		}
+	}
+
+	/// Counts down the invulnerability window, call once per update.
+	pub fn update(&mut self) {
+		if self.invulnerable_timer > 0 {
+			self.invulnerable_timer -= 1;
+		}
+	}
+
+	pub fn take_damage(&mut self, damage: &Damage) -> DamageResult {
+		if self.is_dead() || self.is_invulnerable() || damage.amount == 0 {
+			return DamageResult::Ignored;
+		}
+
+		self.current = self.current.saturating_sub(damage.amount);
+		if self.is_dead() {
+			DamageResult::Killed
+		} else {
+			self.invulnerable_timer = self.invulnerable_frames;
+			DamageResult::Hurt
+		}
+	}
+
+	pub fn heal(&mut self, amount: u32) {
+		if !self.is_dead() {
+			self.current = if self.current + amount > self.max { self.max } else { self.current + amount };
+		}
+	}
+
+	/// Raises the maximum by `amount`, filling the new hit points as well.
+	pub fn raise_max(&mut self, amount: u32) {
+		self.max += amount;
+		self.current += amount;
+	}
+
+	/// Brings an emptied pool back with `amount` hit points.
+	pub fn revive(&mut self, amount: u32) {
+		if self.is_dead() {
+			self.current = if amount > self.max { self.max } else { amount };
+		}
+	}
+
+	/// Sets a new maximum and restores to full.
+	pub fn set_max(&mut self, max: u32) {
+		self.max = max;
+		self.current = max;
+	}
+
+	/// Makes the owner invulnerable for at least `frames` updates, never
+	/// cutting a longer window that is already running short.
+	pub fn start_invulnerability(&mut self, frames: i32) {
+		self.invulnerable_timer = cmp::max(self.invulnerable_timer, frames);
+	}
+
+	pub fn is_invulnerable(&self) -> bool {
+		self.invulnerable_timer > 0
+	}
+
+	pub fn is_dead(&self) -> bool {
+		self.current == 0
+	}
+
+	pub fn get_current(&self) -> u32 {
+		self.current
+	}
+
+	pub fn get_max(&self) -> u32 {
+		self.max
+	}
+}
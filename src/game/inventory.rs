@@ -0,0 +1,76 @@
+use game::graphics;
+use game::powerups;
+
+use game::units;
+
+use sdl2::rect;
+
+// HUD layout (in pixels)
+static SLOT_SIZE:    u32 = 36;
+static SLOT_SPACING: u32 = 40;
+static SLOT_Y:       u32 = 2;
+
+/// Powerups the player has picked up but not used yet.
+///
+/// Each slot holds at most one powerup. Slots keep their position when
+/// emptied so the hotkey for a held item never changes under the player.
+pub struct Inventory {
+	slots: Vec<Option<Box<dyn powerups::Powerup>>>
+}
+
+impl Inventory {
+	pub fn new(capacity: usize) -> Inventory {
+		let mut slots = Vec::new();
+		for _ in 0.. capacity {
+			slots.push(None);
+		}
+		Inventory { slots }
+	}
+
+	/// Stores `powerup` in the first empty slot. A full inventory hands the
+	/// powerup back.
+	pub fn add(&mut self, powerup: Box<dyn powerups::Powerup>) -> Result<(), Box<dyn powerups::Powerup>> {
+		match self.slots.iter().position(|slot| slot.is_none()) {
+			Some(index) => {
+				self.slots[index] = Some(powerup);
+				Ok(())
+			},
+			None => Err(powerup)
+		}
+	}
+
+	/// Removes and returns whatever is held in `slot`.
+	pub fn take(&mut self, slot: usize) -> Option<Box<dyn powerups::Powerup>> {
+		match self.slots.get_mut(slot) {
+			Some(held) => held.take(),
+			None       => None
+		}
+	}
+
+	pub fn is_full(&self) -> bool {
+		self.slots.iter().all(|slot| slot.is_some())
+	}
+
+	pub fn clear(&mut self) {
+		for slot in self.slots.iter_mut() {
+			*slot = None;
+		}
+	}
+
+	pub fn get_capacity(&self) -> usize {
+		self.slots.len()
+	}
+
+	/// Draws a box per slot along the top of the screen with the held items inside.
+	pub fn draw(&self, display: &mut graphics::Graphics, left: u32) {
+		for (i, slot) in self.slots.iter().enumerate() {
+			let x = left + (i as u32 * SLOT_SPACING);
+			display.draw_box(rect::Rect::new(x as i32, SLOT_Y as i32, SLOT_SIZE, SLOT_SIZE), (200, 200, 200));
+			if let Some(ref powerup) = *slot {
+				powerup.draw_icon(display, units::Game((x + 2) as f64), units::Game((SLOT_Y + 2) as f64));
+			}
+			let key = (i + 1).to_string();
+			display.draw_text(&key, rect::Rect::new(x as i32 + 1, (SLOT_Y + SLOT_SIZE - 12) as i32, 8, 12));
+		}
+	}
+}
@@ -0,0 +1,94 @@
+use std::cmp::Reverse;
+use std::fs::File;
+use std::io::Write;
+use std::io::Read;
+use std::path::Path;
+use std::string::String;
+
+static MAX_ENTRIES: usize = 10;
+
+/// One finished run.
+#[derive(Clone,Copy)]
+pub struct Entry {
+	pub score:  i32,
+	/// How long the run lasted, in updates.
+	pub frames: i32,
+	pub kills:  u32
+}
+
+/// The best runs for a mode, kept in a plain text file with one
+/// `score frames kills` line per run, best first.
+pub struct Leaderboard {
+	path:    String,
+	entries: Vec<Entry>
+}
+
+impl Leaderboard {
+	/// Reads the leaderboard at `path`, starting an empty one if the file
+	/// is missing. Lines that can't be read are skipped.
+	pub fn load(path: &str) -> Leaderboard {
+		let mut entries = Vec::new();
+		match File::open(Path::new(path)) {
+			Ok(file) => {
+				let mut contents = String::new();
+				let mut f = file;
+				f.read_to_string(&mut contents).unwrap();
+				for line in contents.lines() {
+					let fields: Vec<&str> = line.split_whitespace().collect();
+					if fields.len() != 3 {
+						println!("skipping leaderboard line: {}", line);
+						continue;
+					}
+					match (fields[0].parse::<i32>(), fields[1].parse::<i32>(), fields[2].parse::<u32>()) {
+						(Ok(score), Ok(frames), Ok(kills)) => {
+							entries.push(Entry { score, frames, kills });
+						},
+						_ => println!("skipping leaderboard line: {}", line)
+					}
+				}
+			},
+			Err(e) => println!("failed to get leaderboard {}: {}", path, e)
+		}
+		entries.sort_by_key(|entry| Reverse(entry.score));
+		entries.truncate(MAX_ENTRIES);
+
+		Leaderboard {
+			path:    String::from(path),
+			entries
+		}
+	}
+
+	/// Adds a finished run and writes the leaderboard back out. Returns the
+	/// run's place (starting at 1) if it made the board.
+	pub fn submit(&mut self, entry: Entry) -> Option<usize> {
+		let place = self.entries.iter().position(|other| entry.score > other.score).unwrap_or(self.entries.len());
+		if place >= MAX_ENTRIES {
+			return None;
+		}
+		self.entries.insert(place, entry);
+		self.entries.truncate(MAX_ENTRIES);
+		self.save();
+		Some(place + 1)
+	}
+
+	pub fn best(&self) -> i32 {
+		match self.entries.first() {
+			Some(entry) => entry.score,
+			None        => 0
+		}
+	}
+
+	fn save(&self) {
+		let mut contents = String::new();
+		for entry in self.entries.iter() {
+			contents = contents + &entry.score.to_string() + " " + &entry.frames.to_string() + " " + &entry.kills.to_string() + "\n";
+		}
+		match File::create(Path::new(&self.path)) {
+			Ok(file) => {
+				let mut f = file;
+				f.write_all(contents.as_bytes()).unwrap();
+			},
+			Err(e) => println!("failed to write leaderboard {}: {}", self.path, e)
+		}
+	}
+}
@@ -0,0 +1,366 @@
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use game::datafile;
+use game::powerups;
+
+// difficulty bands, picked by the director from how stressed the player is
+pub static CALM:      usize = 0;
+pub static NORMAL:    usize = 1;
+pub static INTENSE:   usize = 2;
+pub static DIFFICULTIES: usize = 3;
+
+// kind 0 in a table means nothing is dropped
+pub static NOTHING: u32 = 0;
+
+pub static LOOT_DATA: &str = "assets/loot.txt";
+
+// tables the game rolls by name, campaign levels can pick any other
+pub static STANDARD_POWERUPS: &str = "standard";
+pub static ZOMBIE_DROPS: [&str; 4] = ["slow-zombie", "crazy-zombie", "random-zombie", "cloud-zombie"];
+
+/// One kind of loot in a table.
+#[derive(Clone)]
+pub struct LootEntry {
+	pub kind:    u32,
+	/// First and last level (inclusive) the kind can turn up on.
+	pub levels:  (i32, i32),
+	/// Relative weight when calm, normal and intense.
+	pub weights: [u32; 3]
+}
+
+/// A weighted table of loot kinds.
+///
+/// Each entry is only available within its level range and is weighted by
+/// how hard the game currently is. Tables can guarantee a minimum number of
+/// some kinds whenever they are filled, and keep a pity timer for rare
+/// kinds so a long run of bad luck is always broken. The same table works
+/// for level spawns and for zombie drops.
+#[derive(Clone)]
+pub struct LootTable {
+	pub name: String,
+	entries:  Vec<LootEntry>,
+	minimums: Vec<(u32, u32)>,
+	pity:     Vec<(u32, u32)>,
+	misses:   Vec<u32>
+}
+
+impl LootTable {
+	pub fn new(name: &str,
+	           entries: Vec<LootEntry>,
+	           minimums: Vec<(u32, u32)>,
+	           pity: Vec<(u32, u32)>) -> LootTable {
+		LootTable {
+			name:     String::from(name),
+			entries,
+			minimums,
+			misses:   vec![0; pity.len()],
+			pity
+		}
+	}
+
+	/// A fresh copy of the table called `name` in `tables`.
+	pub fn named(tables: &[LootTable], name: &str) -> Option<LootTable> {
+		tables.iter().find(|table| table.name == name).map(|table| {
+			let mut table = table.clone();
+			table.reset_pity();
+			table
+		})
+	}
+
+	/// The table used for the powerups spread over each level.
+	pub fn powerups(tables: &[LootTable]) -> LootTable {
+		LootTable::named(tables, STANDARD_POWERUPS).unwrap()
+	}
+
+	/// The table rolled when a zombie of `zombie_type` is killed.
+	pub fn zombie_drops(tables: &[LootTable], zombie_type: i32) -> LootTable {
+		let name = match zombie_type {
+			1 => ZOMBIE_DROPS[0],
+			2 => ZOMBIE_DROPS[1],
+			3 => ZOMBIE_DROPS[2],
+			_ => ZOMBIE_DROPS[3]
+		};
+		LootTable::named(tables, name).unwrap()
+	}
+
+	fn lists(&self, kind: u32) -> bool {
+		self.entries.iter().any(|entry| entry.kind == kind)
+	}
+
+	/// Picks a kind for `level` at `difficulty`, or `None` when nothing
+	/// (or no kind at all) comes up.
+	pub fn roll(&mut self, rng: &mut StdRng, level: i32, difficulty: usize) -> Option<u32> {
+		let difficulty = if difficulty < DIFFICULTIES { difficulty } else { DIFFICULTIES - 1 };
+
+		// a rare kind that has been missing for too long comes up for sure
+		let mut forced: Option<u32> = None;
+		for (i, &(kind, threshold)) in self.pity.iter().enumerate() {
+			if self.misses[i] >= threshold && self.is_available(kind, level) {
+				forced = Some(kind);
+				break;
+			}
+		}
+
+		let kind = match forced {
+			Some(kind) => Some(kind),
+			None       => self.pick(rng, level, difficulty)
+		};
+		if let Some(kind) = kind {
+			self.record(kind);
+		}
+		match kind {
+			Some(kind) if kind == NOTHING => None,
+			_                            => kind
+		}
+	}
+
+	/// Rolls kinds for `level` until there are `count` of them, starting
+	/// with the guaranteed minimums.
+	pub fn fill(&mut self, rng: &mut StdRng, count: u32, level: i32, difficulty: usize) -> Vec<u32> {
+		let mut kinds = Vec::new();
+		for &(kind, minimum) in self.minimums.iter() {
+			if self.is_available(kind, level) {
+				for _ in 0.. minimum {
+					kinds.push(kind);
+				}
+			}
+		}
+		for &kind in kinds.iter() {
+			self.record(kind);
+		}
+		while (kinds.len() as u32) < count {
+			match self.roll(rng, level, difficulty) {
+				Some(kind) => { kinds.push(kind); },
+				None       => { break; }
+			}
+		}
+		kinds
+	}
+
+	/// Forgets the run of bad luck, e.g. when a new game starts.
+	pub fn reset_pity(&mut self) {
+		for misses in self.misses.iter_mut() {
+			*misses = 0;
+		}
+	}
+
+	fn is_available(&self, kind: u32, level: i32) -> bool {
+		self.entries.iter().any(|entry| {
+			let (first, last) = entry.levels;
+			entry.kind == kind && level >= first && level <= last
+		})
+	}
+
+	fn pick(&self, rng: &mut StdRng, level: i32, difficulty: usize) -> Option<u32> {
+		let available: Vec<&LootEntry> = self.entries.iter().filter(|entry| {
+			let (first, last) = entry.levels;
+			level >= first && level <= last && entry.weights[difficulty] > 0
+		}).collect();
+		let total: u32 = available.iter().map(|entry| entry.weights[difficulty]).sum();
+		if total == 0 {
+			return None;
+		}
+
+		let mut roll = rng.gen_range(0..total);
+		for entry in available.iter() {
+			if roll < entry.weights[difficulty] {
+				return Some(entry.kind);
+			}
+			roll -= entry.weights[difficulty];
+		}
+		None
+	}
+
+	/// Counts a roll towards every pity timer, resetting the one for `kind`.
+	fn record(&mut self, kind: u32) {
+		for (i, &(pity_kind, _)) in self.pity.iter().enumerate() {
+			self.misses[i] = if pity_kind == kind { 0 } else { self.misses[i] + 1 };
+		}
+	}
+}
+
+/// Reads every loot table from the data file at `path`.
+///
+/// Same format as the other data files: `keyword values...` lines with `#`
+/// starting a comment, and a broken file stops the game with a message
+/// saying what is wrong.
+pub fn load_loot(path: &str) -> Vec<LootTable> {
+	let mut tables: Vec<LootTable> = Vec::new();
+	datafile::read_lines(path, "loot tables", |line| {
+		let rest = &line.rest;
+		if line.keyword == "table" {
+			tables.push(LootTable::new(&rest.join(" "), Vec::new(), Vec::new(), Vec::new()));
+			return;
+		}
+		let table = line.block(&mut tables, "table");
+
+		let kind = match rest.first().and_then(|name| powerups::kind_named(name)) {
+			Some(kind) => kind,
+			None       => line.error("unknown powerup")
+		};
+		let numbers: Vec<u32> = rest[1..].iter().map(|word| match word.parse::<u32>() {
+			Ok(number) => number,
+			Err(_)     => line.error("expected a number")
+		}).collect();
+		let expected = match line.keyword {
+			"loot"              => 5,
+			"minimum" | "pity" => 1,
+			_                  => line.error("unknown keyword")
+		};
+		if numbers.len() != expected {
+			line.error("wrong number of values");
+		}
+
+		match line.keyword {
+			"loot" => {
+				if numbers[0] < 1 || numbers[0] > numbers[1] {
+					line.error("levels should run from the first to the last");
+				}
+				table.entries.push(LootEntry {
+					kind,
+					levels:  (numbers[0] as i32, numbers[1] as i32),
+					weights: [numbers[2], numbers[3], numbers[4]]
+				});
+			},
+			_ if !table.lists(kind) => line.error("expected `loot` for this kind first"),
+			"minimum" => { table.minimums.push((kind, numbers[0])); },
+			_ => {
+				table.pity.push((kind, numbers[0]));
+				table.misses.push(0);
+			}
+		}
+	});
+
+	for table in tables.iter() {
+		if table.entries.is_empty() {
+			panic!("{}: table {} has no loot", path, table.name);
+		}
+	}
+	for name in ZOMBIE_DROPS.iter().chain(Some(&STANDARD_POWERUPS)) {
+		if !tables.iter().any(|table| table.name == *name) {
+			panic!("{}: no {} table defined", path, name);
+		}
+	}
+	tables
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rand::SeedableRng;
+
+	fn entry(kind: u32, first_level: i32, weight: u32) -> LootEntry {
+		LootEntry { kind, levels: (first_level, 99), weights: [weight, weight, weight] }
+	}
+
+	fn late_table(minimums: Vec<(u32, u32)>) -> LootTable {
+		LootTable::new("late", vec![entry(5, 3, 1)], minimums, Vec::new())
+	}
+
+	// nothing ever comes up by weight, so only pity can produce kind 4
+	fn pity_table() -> LootTable {
+		LootTable::new("pity", vec![entry(NOTHING, 1, 1), entry(4, 1, 0)], Vec::new(), vec![(4, 3)])
+	}
+
+	#[test]
+	fn load_loot_reads_every_table() {
+		let tables = load_loot(LOOT_DATA);
+		for name in ["standard", "scarce", "armory"].iter().chain(ZOMBIE_DROPS.iter()) {
+			assert!(LootTable::named(&tables, name).is_some(), "missing {}", name);
+		}
+		assert!(LootTable::named(&tables, "treasure").is_none());
+		assert_eq!(LootTable::zombie_drops(&tables, 2).name, "crazy-zombie");
+		assert_eq!(LootTable::zombie_drops(&tables, 9).name, "cloud-zombie");
+	}
+
+	#[test]
+	fn fill_starts_with_minimums() {
+		let mut table = LootTable::powerups(&load_loot(LOOT_DATA));
+		let mut rng = StdRng::seed_from_u64(1);
+		assert_eq!(table.fill(&mut rng, 0, 1, CALM), vec![11, 7]);
+		let kinds = table.fill(&mut rng, 10, 1, NORMAL);
+		assert_eq!(kinds.len(), 10);
+		assert_eq!(&kinds[..2], &[11, 7]);
+	}
+
+	#[test]
+	fn level_ranges_hold_back_late_kinds() {
+		// nukes, machine guns and heart containers only turn up from level 2
+		let mut table = LootTable::powerups(&load_loot(LOOT_DATA));
+		let mut rng = StdRng::seed_from_u64(1);
+		let kinds = table.fill(&mut rng, 200, 1, INTENSE);
+		assert!(!kinds.iter().any(|&kind| kind == 6 || kind == 10 || kind == 12));
+	}
+
+	#[test]
+	fn zombie_drops_are_mostly_nothing() {
+		let tables = load_loot(LOOT_DATA);
+		let mut rng = StdRng::seed_from_u64(1);
+		for zombie_type in 1.. ZOMBIE_DROPS.len() as i32 + 1 {
+			let mut table = LootTable::zombie_drops(&tables, zombie_type);
+			let drops = (0.. 100).filter(|_| table.roll(&mut rng, 1, CALM).is_some()).count();
+			assert!(drops > 0 && drops < 75, "{} dropped {} times", table.name, drops);
+		}
+	}
+
+	#[test]
+	fn fill_skips_minimums_not_yet_available() {
+		let mut table = late_table(vec![(5, 2)]);
+		let mut rng = StdRng::seed_from_u64(1);
+		assert!(table.fill(&mut rng, 0, 1, CALM).is_empty());
+		assert_eq!(table.fill(&mut rng, 0, 3, CALM), vec![5, 5]);
+	}
+
+	#[test]
+	fn roll_forces_pity_kind() {
+		let mut table = pity_table();
+		let mut rng = StdRng::seed_from_u64(1);
+		for _ in 0.. 3 {
+			assert_eq!(table.roll(&mut rng, 1, CALM), None);
+		}
+		assert_eq!(table.roll(&mut rng, 1, CALM), Some(4));
+		assert_eq!(table.roll(&mut rng, 1, CALM), None);
+	}
+
+	#[test]
+	fn reset_pity_restarts_the_count() {
+		let mut table = pity_table();
+		let mut rng = StdRng::seed_from_u64(1);
+		for _ in 0.. 3 {
+			table.roll(&mut rng, 1, CALM);
+		}
+		table.reset_pity();
+		assert_eq!(table.roll(&mut rng, 1, CALM), None);
+	}
+
+	#[test]
+	fn named_tables_start_without_pity() {
+		let mut tables = vec![pity_table()];
+		let mut rng = StdRng::seed_from_u64(1);
+		for _ in 0.. 3 {
+			tables[0].roll(&mut rng, 1, CALM);
+		}
+		let mut fresh = LootTable::named(&tables, "pity").unwrap();
+		assert_eq!(fresh.roll(&mut rng, 1, CALM), None);
+	}
+
+	#[test]
+	fn fill_hands_out_an_owed_pity_kind() {
+		let mut table = pity_table();
+		let mut rng = StdRng::seed_from_u64(1);
+		for _ in 0.. 3 {
+			table.roll(&mut rng, 1, CALM);
+		}
+		// a table that is owed its rare kind hands it out first
+		assert_eq!(table.fill(&mut rng, 1, 1, CALM), vec![4]);
+	}
+
+	#[test]
+	fn roll_respects_level_range_and_clamps_difficulty() {
+		let mut table = late_table(Vec::new());
+		let mut rng = StdRng::seed_from_u64(1);
+		assert_eq!(table.roll(&mut rng, 1, CALM), None);
+		assert_eq!(table.roll(&mut rng, 3, DIFFICULTIES + 5), Some(5));
+	}
+}
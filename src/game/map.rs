@@ -1,10 +1,12 @@
 use std::vec::Vec;
 use std::rc::Rc;
-use rand;
 use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 
 use game;
 use game::backdrop;
+use game::datafile;
 use game::graphics;
 use game::sprite;
 use game::units;
@@ -12,12 +14,64 @@ use game::units;
 use game::collisions::Rectangle;
 use game::units::{AsGame,AsTile};
 
+pub static ROWS: u32 = 60;
+pub static COLS: u32 = 60;
+// the top left corner is kept clear for the players and the vehicle
+static START_AREA: u32 = 9;
+
 #[derive(PartialEq,Eq,Clone)]
 pub enum TileType {
 	Air,
 	Wall
 }
 
+/// Where a map's walls go.
+#[derive(Clone)]
+pub enum Layout {
+	/// Walls only around the edge.
+	Open,
+	/// Walls scattered about at random.
+	Scattered,
+	/// Walls wherever an authored layout file has them.
+	Authored(Vec<Vec<TileType>>)
+}
+
+/// Reads an authored map from the layout file at `path`.
+///
+/// Every line is a row of the map, `W` for a wall and `.` for open ground.
+/// The map has to be walled all the way round and keep the starting corner
+/// open, a broken layout stops the game with a message saying what is wrong.
+pub fn load_layout(path: &str) -> Layout {
+	let mut rows: Vec<Vec<TileType>> = Vec::new();
+	datafile::read_lines(path, "map", |line| {
+		if !line.rest.is_empty() || line.keyword.len() != COLS as usize {
+			line.error("rows should be 60 tiles wide");
+		}
+		rows.push(line.keyword.chars().map(|tile| match tile {
+			'W' => TileType::Wall,
+			'.' => TileType::Air,
+			_   => line.error("tiles should be W or .")
+		}).collect());
+	});
+
+	if rows.len() != ROWS as usize {
+		panic!("{}: maps should be 60 rows tall", path);
+	}
+	for (i, row) in rows.iter().enumerate() {
+		for (j, tile) in row.iter().enumerate() {
+			let edge = i == 0 || i == ROWS as usize - 1 || j == 0 || j == COLS as usize - 1;
+			let start = i < START_AREA as usize && j < START_AREA as usize;
+			if edge && *tile != TileType::Wall {
+				panic!("{}: row {} has a gap in the outer wall", path, i + 1);
+			}
+			if start && !edge && *tile != TileType::Air {
+				panic!("{}: row {} blocks the starting corner", path, i + 1);
+			}
+		}
+	}
+	Layout::Authored(rows)
+}
+
 pub struct CollisionTile {
 	pub tile_type:  TileType,
 	pub row:        units::Tile,
@@ -61,10 +115,9 @@ pub struct Map {
 
 impl Map {
 	/// Will initialize a map (60 * 60) tiles:
-	pub fn load_map(graphics: &mut graphics::Graphics, level: i32) -> Map {
-		static ROWS: u32 = 60;
-		static COLS: u32 = 60; 
-
+	/// random layouts are generated from `seed`, so the same seed always
+	/// gives the same map.
+	pub fn load_map(graphics: &mut graphics::Graphics, layout: &Layout, seed: u64) -> Map {
 		let map_path =  "assets/base/Stage/PrtCave.bmp".to_string();
 		let sprite   =  Rc::new(
 			Box::new( sprite::Sprite::new(
@@ -78,8 +131,8 @@ impl Map {
 		let blank_tile = Tile::new();
 		let wall_tile = Tile::from_sprite(sprite, TileType::Wall);
 		let mut tile_vec: Vec<Box<Vec<Box<Tile>>>> = Vec::new();
-		match level { 
-			1 => { 
+		match *layout { 
+			Layout::Open => { 
 				for i in 0.. ROWS {
 					let mut vec = Box::new( Vec::new() );
 					for j in 0.. COLS {
@@ -94,18 +147,16 @@ impl Map {
 					tile_vec.push(vec);
 				}
 			},
-			_ => {
-				let mut rng = rand::thread_rng();
+			Layout::Scattered => {
+				let mut rng = StdRng::seed_from_u64(seed);
 				let rand_num_cols = rng.gen_range(1..30);
 				let rand_num_rows = rng.gen_range(1..30);
 				let mut rand_cols: Vec<i32> = Vec::new();
 				let mut rand_rows: Vec<i32> = Vec::new();
 				for _ in 0.. rand_num_cols {
-					let mut rng = rand::thread_rng();
 					rand_cols.push( rng.gen_range(1..60) );
 				}
 				for _ in 0.. rand_num_rows {
-					let mut rng = rand::thread_rng();
 					rand_rows.push( rng.gen_range(1..60) );
 				}
 				for i in 0.. ROWS {
@@ -113,7 +164,7 @@ impl Map {
 					for j in 0.. COLS {
 						// make the border
 						if i == ROWS - 1 || i == 0 || j == 0 || j == COLS - 1 || ( rand_rows.contains(&(i as i32)) && rand_cols.contains(&(j as i32)) ) {
-							if (i > 0 && i < 9) && (j > 0 && j < 9) {
+							if (i > 0 && i < START_AREA) && (j > 0 && j < START_AREA) {
 								vec.push( Box::new(blank_tile.clone()) );
 							} else {
 								vec.push( Box::new(wall_tile.clone()) );
@@ -125,11 +176,20 @@ impl Map {
 					}
 					tile_vec.push(vec);
 				}
+			},
+			Layout::Authored(ref rows) => {
+				for row in rows.iter() {
+					let vec = Box::new( row.iter().map(|tile_type| match *tile_type {
+						TileType::Wall => Box::new(wall_tile.clone()),
+						TileType::Air  => Box::new(blank_tile.clone())
+					}).collect() );
+					tile_vec.push(vec);
+				}
 			}
 		}
-		let background: backdrop::FixedBackdrop = match level {
-			1 => { backdrop::FixedBackdrop::new("assets/base/bkBlue.bmp".to_string(), graphics) },
-			_ => { backdrop::FixedBackdrop::new("assets/base/bkRed.bmp".to_string(), graphics) },
+		let background: backdrop::FixedBackdrop = match *layout {
+			Layout::Open => { backdrop::FixedBackdrop::new("assets/base/bkBlue.bmp".to_string(), graphics) },
+			_            => { backdrop::FixedBackdrop::new("assets/base/bkRed.bmp".to_string(), graphics) },
 		};
 		let map = Map {
 			background: background,
@@ -202,6 +262,28 @@ impl Map {
 		}
 	}
 
+	/// Whether a straight line between two points on the map crosses no walls.
+	/// The line is sampled every half tile, which is fine enough to stop it
+	/// slipping through a single wall tile.
+	pub fn line_of_sight(&self, from: (units::Game, units::Game), to: (units::Game, units::Game)) -> bool {
+		let ((units::Game(x0), units::Game(y0)), (units::Game(x1), units::Game(y1))) = (from, to);
+		let units::Game(step) = units::HalfTile(1).to_game();
+		let length = ((x1 - x0) * (x1 - x0) + (y1 - y0) * (y1 - y0)).sqrt();
+		let steps = (length / step).ceil() as u32;
+
+		for i in 0.. steps + 1 {
+			let t = if steps == 0 { 0.0 } else { i as f64 / steps as f64 };
+			let units::Tile(row) = units::Game(y0 + (y1 - y0) * t).to_tile();
+			let units::Tile(col) = units::Game(x0 + (x1 - x0) * t).to_tile();
+			match self.tiles.get(row as usize).and_then(|tiles| tiles.get(col as usize)) {
+				Some(tile) if tile.tile_type == TileType::Wall => { return false; },
+				_ => {}
+			}
+		}
+
+		true
+	}
+
 	/// no-op for demo map
 	pub fn update(&mut self) {
 		/* 
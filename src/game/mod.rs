@@ -4,17 +4,31 @@ pub use game::game::{SCREEN_WIDTH, LEVEL_WIDTH, SCREEN_HEIGHT, LEVEL_HEIGHT};
 pub mod game;
 pub mod units;
 pub mod backdrop;
+pub mod campaign;
 pub mod collisions;
 pub mod common;
+pub mod compass;
+pub mod daily;
+pub mod datafile;
+pub mod director;
 pub mod enemies;
 pub mod graphics;
+pub mod health;
 pub mod input;
+pub mod inventory;
+pub mod leaderboard;
+pub mod loot;
 pub mod map;
+pub mod mode;
+pub mod noise;
 pub mod player;
+pub mod scent;
 pub mod sprite;
-pub mod heli;
-pub mod car;
+pub mod status;
+pub mod survival;
 pub mod vehicle;
 pub mod powerups;
+pub mod projectile;
 pub mod traps;
+pub mod weapons;
 pub mod music;
\ No newline at end of file
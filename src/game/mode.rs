@@ -0,0 +1,43 @@
+/// The rules a run is played under, picked from the title screen.
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum Mode {
+	/// Build the vehicle and escape in it before the timer runs out.
+	Escape,
+	/// The escape levels from the campaign data, in order.
+	Campaign,
+	/// No vehicle, stay alive for as long as possible.
+	Survival,
+	/// One escape level a day, the same for everybody, scored once.
+	Daily
+}
+
+impl Mode {
+	/// The mode after this one when cycling through them on the title screen.
+	pub fn next(&self) -> Mode {
+		match *self {
+			Mode::Escape   => Mode::Campaign,
+			Mode::Campaign => Mode::Survival,
+			Mode::Survival => Mode::Daily,
+			Mode::Daily    => Mode::Escape
+		}
+	}
+
+	pub fn name(&self) -> &'static str {
+		match *self {
+			Mode::Escape   => "ESCAPE",
+			Mode::Campaign => "CAMPAIGN",
+			Mode::Survival => "SURVIVAL",
+			Mode::Daily    => "DAILY CHALLENGE"
+		}
+	}
+
+	/// Whether each level has a vehicle to build and escape in.
+	pub fn has_vehicle(&self) -> bool {
+		*self != Mode::Survival
+	}
+
+	/// Whether the level timer counts down to a game over.
+	pub fn is_timed(&self) -> bool {
+		*self != Mode::Survival
+	}
+}
@@ -0,0 +1,37 @@
+use game::units;
+use game::units::AsGame;
+
+// how far each kind of noise carries
+pub static GUNFIRE:      units::Tile = units::Tile(10);
+pub static EXPLOSION:    units::Tile = units::Tile(25);
+pub static TRAP_SNAP:    units::Tile = units::Tile(8);
+pub static PART_INSTALL: units::Tile = units::Tile(12);
+pub static SPRINTING:    units::Tile = units::Tile(5);
+pub static TRIPWIRE:     units::Tile = units::Tile(20);
+pub static MAGNET:       units::Tile = units::Tile(40);
+
+// how long a zombie keeps heading for a noise before giving up (in updates)
+pub static INVESTIGATE_FRAMES: i32 = 300;
+
+/// A sound made somewhere on the map. Zombies within `loudness` of it will
+/// go and investigate.
+pub struct Noise {
+	pub x:        units::Game,
+	pub y:        units::Game,
+	pub loudness: units::Game
+}
+
+impl Noise {
+	pub fn new(x: units::Game, y: units::Game, loudness: units::Tile) -> Noise {
+		Noise { x, y, loudness: loudness.to_game() }
+	}
+
+	/// Whether something standing at `x`, `y` is close enough to hear this.
+	pub fn is_heard_at(&self, x: units::Game, y: units::Game) -> bool {
+		let units::Game(xs) = (x - self.x) * (x - self.x);
+		let units::Game(ys) = (y - self.y) * (y - self.y);
+		let units::Game(loudness) = self.loudness;
+
+		(xs + ys).sqrt() <= loudness
+	}
+}
@@ -1,11 +1,18 @@
 use std::collections::HashMap;
 
 use game::graphics;
+use game::health;
 use game::sprite;
+use game::status;
+use game::vehicle;
 
+use game::collisions::Rectangle;
 use game::map;
+use game::scent;
+use game::weapons;
 
 use game::units;
+use game::units::AsGame;
 
 use game::common;
 
@@ -22,12 +29,50 @@ static HIT_FPS:             units::Fps    = 10;
 
 static WALKING_ACCEL:  units::Acceleration  = units::Acceleration(0.00083007812);
 static MAX_VELOCITY: units::Velocity      = units::Velocity(0.17859375);
+static SPRINT_ACCEL:   units::Acceleration  = units::Acceleration(0.00124511718);
+static SPRINT_MAX_VELOCITY: units::Velocity    = units::Velocity(0.26789062);
+static EXHAUSTED_MAX_VELOCITY: units::Velocity = units::Velocity(0.10859375);
+
+// stamina (drain and regen are per update)
+static MAX_STAMINA:         f64 = 100.0;
+static SPRINT_DRAIN:        f64 = 0.5;
+static WALKING_REGEN:       f64 = 0.2;
+static STANDING_REGEN:      f64 = 0.4;
+static EXHAUSTION_FRAMES:   i32 = 180;
+static STAMINA_BOOST_FRAMES: i32 = 600;
+
+// each carried part slows the player by this much, down to a floor
+static ENCUMBRANCE_PER_PART: f64 = 0.15;
+static MIN_ENCUMBERED_SPEED: f64 = 0.4;
+
+// health
+static MAX_HP:          u32 = 3;
+static IMMUNITY_FRAMES: i32 = 60;
 
 // motion
 static STAND_FRAME: units::Tile   = units::Tile(0);
 static TELEPORT_FRAME: units::Tile = units::Tile(3);
 static TELEPORT_ANIMATION_FRAME: units::Tile = units::Tile(1);
+static TELEPORT_FRAMES: i32 = 20;
 static HIT_FRAME: units::Tile = units::Tile(3);
+static DODGE_FRAME: units::Tile = units::Tile(8);
+static DODGE_NUM_FRAMES: units::Frame = 2;
+static DODGE_FPS:        units::Fps   = 15;
+
+// dodge roll
+static DODGE_VELOCITY:       units::Velocity = units::Velocity(0.45);
+static DODGE_FRAMES:         i32 = 12;
+static DODGE_IMMUNITY_FRAMES: i32 = 18;
+static DODGE_COOLDOWN:       i32 = 60;
+static DODGE_STAMINA:        f64 = 20.0;
+
+// springs launch the player like a long roll
+static FLING_FRAMES:         i32 = 30;
+
+// standing still against a wall for this long hides the player's scent
+static HIDE_FRAMES:          i32 = 90;
+// zombies can't fall back on the player's position for this long after a teleport
+static TRAIL_LOST_FRAMES:    i32 = 180;
 
 // horizontal facing (Facing)
 static FACING_WEST: units::Tile  = units::Tile(0 + CHAR_OFFSET);
@@ -40,13 +85,20 @@ pub struct Player {
 	cricket_sprites: HashMap<MotionTup, Box<dyn sprite::Updatable<units::Game>>>,
 	teleport_sprites: HashMap<MotionTup, Box<dyn sprite::Updatable<units::Game>>>,
 	hit_sprites: HashMap<MotionTup, Box<dyn sprite::Updatable<units::Game>>>,
-	cricket_bat: bool,
-	teleport_timer: i32, 
-	immunity_timer: i32,
-	health: u32,
-	follow_x: units::Game,
-	follow_y: units::Game,
-	update_count: i32
+	dodge_sprites: HashMap<MotionTup, Box<dyn sprite::Updatable<units::Game>>>,
+	weapons: Vec<weapons::Weapon>,
+	current_weapon: usize,
+	scent: scent::ScentTrail,
+	hide_timer: i32,
+	trail_lost_timer: i32,
+	stamina: f64,
+	sprint_held: bool,
+	sprinting: bool,
+	exhausted_timer: i32,
+	stamina_boost_timer: i32,
+	dodge_timer: i32,
+	dodge_cooldown: i32,
+	parts: Vec<Box<dyn vehicle::Part>>
 }
 
 impl Player {
@@ -60,21 +112,31 @@ impl Player {
 		let cricket = HashMap::<MotionTup, Box<dyn sprite::Updatable<_>>>::new();
 		let teleport = HashMap::<MotionTup, Box<dyn sprite::Updatable<_>>>::new();
 		let hit = HashMap::<MotionTup, Box<dyn sprite::Updatable<_>>>::new();
+		let dodge = HashMap::<MotionTup, Box<dyn sprite::Updatable<_>>>::new();
 		// construct new player
 		let mut new_player = Player{
 			character: common::Character::new(x, y),
 			cricket_sprites: cricket,
 			teleport_sprites: teleport,
 			hit_sprites: hit,
-			cricket_bat: false,
-			teleport_timer: 0, 
-			immunity_timer: 0,
-			health: 3, 
-			follow_x: units::Game(0 as f64),
-			follow_y: units::Game(0 as f64),
-			update_count: 0
+			dodge_sprites: dodge,
+			weapons: Vec::new(),
+			current_weapon: 0,
+			scent: scent::ScentTrail::new(),
+			hide_timer: 0,
+			trail_lost_timer: 0,
+			stamina: MAX_STAMINA,
+			sprint_held: false,
+			sprinting: false,
+			exhausted_timer: 0,
+			stamina_boost_timer: 0,
+			dodge_timer: 0,
+			dodge_cooldown: 0,
+			parts: Vec::new()
 		};
 
+		new_player.character.health = health::Health::new(MAX_HP, IMMUNITY_FRAMES);
+
 		// load sprites for every possible movement tuple.
 		for motion in sprite::MOTIONS.iter() {
 			for facing in sprite::FACINGS.iter() {
@@ -93,20 +155,17 @@ impl Player {
 		self.character.elapsed_time = elapsed_time;
 
 		// update timers
-		if self.teleport_timer > 0 {
-			self.teleport_timer = self.teleport_timer - 1;
-		}
-		if self.immunity_timer > 0 {
-			self.immunity_timer = self.immunity_timer - 1;
+		self.character.health.update();
+		self.update_stamina();
+		self.update_dodge();
+		for weapon in self.weapons.iter_mut() {
+			weapon.update();
 		}
 
-		// update the last know location of player for enemies that chase
-		if self.update_count == 10 {
-			self.follow_x = self.character.map_center_x();
-			self.follow_y = self.character.map_center_y();
-			self.update_count = 0;
-		} else {
-			self.update_count = self.update_count + 1;
+		// leave a scent trail for enemies that chase, unless hiding
+		self.update_hiding(map);
+		if !self.is_hidden() {
+			self.scent.update(self.character.map_center_x(), self.character.map_center_y());
 		}
 
 		// update sprite
@@ -115,10 +174,12 @@ impl Player {
 		if self.has_bat() {
 			self.cricket_sprites.get_mut(&self.character.movement).unwrap().update(elapsed_time);
 		}
-		if self.teleport_timer > 0 {
+		if self.is_teleporting() {
 			self.teleport_sprites.get_mut(&self.character.movement).unwrap().update(elapsed_time);
 		}
-		if self.immunity_timer > 0 {
+		if self.is_dodging() {
+			self.dodge_sprites.get_mut(&self.character.movement).unwrap().update(elapsed_time);
+		} else if self.is_immune() {
 			self.hit_sprites.get_mut(&self.character.movement).unwrap().update(elapsed_time);
 		}
 		if self.character.is_killed() {
@@ -126,8 +187,100 @@ impl Player {
 		}
 
 		// run physics sim
-		self.character.update_x(map, WALKING_ACCEL, MAX_VELOCITY);
-		self.character.update_y(map, WALKING_ACCEL, MAX_VELOCITY);
+		let (acceleration, max_velocity) = if self.is_dodging() {
+			(SPRINT_ACCEL, DODGE_VELOCITY)
+		} else if self.is_exhausted() {
+			(WALKING_ACCEL, EXHAUSTED_MAX_VELOCITY)
+		} else if self.sprinting {
+			(SPRINT_ACCEL, SPRINT_MAX_VELOCITY)
+		} else {
+			(WALKING_ACCEL, MAX_VELOCITY)
+		};
+		let max_velocity = if self.is_dodging() { max_velocity } else { self.encumber(max_velocity) };
+		self.character.update_x(map, acceleration, max_velocity);
+		self.character.update_y(map, acceleration, max_velocity);
+	}
+
+	/// Scales `max_velocity` down for the parts being carried.
+	fn encumber(&self, max_velocity: units::Velocity) -> units::Velocity {
+		let units::Velocity(velocity) = max_velocity;
+		let factor = 1.0 - (ENCUMBRANCE_PER_PART * self.parts.len() as f64);
+		units::Velocity(velocity * if factor < MIN_ENCUMBERED_SPEED { MIN_ENCUMBERED_SPEED } else { factor })
+	}
+
+	/// Counts down the roll and its cooldown. When the roll ends the player is
+	/// brought back down to walking speed.
+	fn update_dodge(&mut self) {
+		if self.dodge_cooldown > 0 {
+			self.dodge_cooldown -= 1;
+		}
+		if self.dodge_timer > 0 {
+			self.dodge_timer -= 1;
+			if self.dodge_timer == 0 {
+				self.character.velocity_x = units::min(units::max(self.character.velocity_x, -MAX_VELOCITY), MAX_VELOCITY);
+				self.character.velocity_y = units::min(units::max(self.character.velocity_y, -MAX_VELOCITY), MAX_VELOCITY);
+			}
+		}
+	}
+
+	/// Counts how long the player has kept still against a wall. Once they
+	/// have been there long enough they are hidden and their trail is wiped.
+	fn update_hiding(&mut self, map: &map::Map) {
+		if self.trail_lost_timer > 0 {
+			self.trail_lost_timer -= 1;
+		}
+		let moving = self.character.accel_x != 0 || self.character.accel_y != 0 || self.is_dodging();
+		if moving || self.is_down() || !self.beside_wall(map) {
+			self.hide_timer = 0;
+			return;
+		}
+		if self.hide_timer < HIDE_FRAMES {
+			self.hide_timer += 1;
+			if self.is_hidden() {
+				self.break_trail();
+			}
+		}
+	}
+
+	/// Whether there is a wall within half a tile of the player.
+	fn beside_wall(&self, map: &map::Map) -> bool {
+		let own = self.character.damage_rectangle();
+		let reach = units::HalfTile(1).to_game();
+		let surroundings = Rectangle {
+			x:      own.x - reach,
+			y:      own.y - reach,
+			width:  own.width + reach + reach,
+			height: own.height + reach + reach
+		};
+		map.get_colliding_tiles(&surroundings).iter().any(|tile| tile.tile_type == map::TileType::Wall)
+	}
+
+	/// Drains stamina while sprinting and regenerates it otherwise, faster when
+	/// standing still. Running out leaves the player exhausted for a while.
+	fn update_stamina(&mut self) {
+		if self.exhausted_timer > 0 {
+			self.exhausted_timer -= 1;
+		}
+		if self.stamina_boost_timer > 0 {
+			self.stamina_boost_timer -= 1;
+		}
+
+		let moving = self.character.accel_x != 0 || self.character.accel_y != 0;
+		self.sprinting = self.sprint_held && moving && !self.is_exhausted();
+
+		if self.sprinting {
+			if self.stamina_boost_timer == 0 {
+				self.stamina -= SPRINT_DRAIN;
+			}
+			if self.stamina <= 0.0 {
+				self.stamina = 0.0;
+				self.sprinting = false;
+				self.exhausted_timer = EXHAUSTION_FRAMES;
+			}
+		} else {
+			let regen = if moving { WALKING_REGEN } else { STANDING_REGEN };
+			self.stamina = if self.stamina + regen > MAX_STAMINA { MAX_STAMINA } else { self.stamina + regen };
+		}
 	}
 
 	/// Loads a sprite for the selected `movement`, stores it in the player's sprite map.
@@ -235,6 +388,22 @@ impl Player {
 				}
 			}
 		});
+		self.dodge_sprites.insert(movement, {
+			let file_path = "assets/MyChar.bmp".to_string();
+			let (_, facing) = movement;
+
+			let facing_frame = match facing {
+				sprite::Facing::West => FACING_WEST,
+				sprite::Facing::East => FACING_EAST
+			};
+
+			Box::new( sprite::AnimatedSprite::new(
+				graphics, file_path,
+				(DODGE_FRAME, facing_frame),
+				(units::Tile(1), units::Tile(1)),
+				DODGE_NUM_FRAMES, DODGE_FPS
+			).unwrap() ) as Box<dyn sprite::Updatable<_>>
+		});
 		self.character.sprites.insert(movement, {
 			let file_path = "assets/MyChar.bmp".to_string();
 			let (_, facing) = movement;
@@ -271,10 +440,12 @@ impl Player {
 
 	//draw the player
 	pub fn draw(&self, display: &mut graphics::Graphics) {
-		if self.cricket_bat {
+		if self.is_dodging() {
+			self.dodge_sprites.get(&self.character.movement).unwrap().draw(display, (self.character.x, self.character.y));
+		} else if self.has_bat() {
 			self.cricket_sprites.get(&self.character.movement).unwrap().draw(display, (self.character.x, self.character.y));
-		} else if self.teleport_timer > 0 {
-			match self.teleport_timer {
+		} else if self.is_teleporting() {
+			match self.character.effects.remaining(status::Effect::Teleporting) {
 				x if x > 15 => {
 					let asset_path = "assets/base/teleport.bmp".to_string();
 					let motion_frame = TELEPORT_ANIMATION_FRAME;
@@ -289,16 +460,23 @@ impl Player {
 				},
 				_ => { self.teleport_sprites.get(&self.character.movement).unwrap().draw(display, (self.character.x, self.character.y)); }
 			}
-		} else if self.immunity_timer > 0 {
+		} else if self.is_immune() {
 			self.hit_sprites.get(&self.character.movement).unwrap().draw(display, (self.character.x, self.character.y));
 		} else {
 			self.character.draw(display);
 		}
+
+		if let Some(weapon) = self.get_weapon() {
+			let (_, facing) = self.character.movement;
+			weapon.draw(display, self.character.x, self.character.y, facing);
+		}
+		self.character.effects.draw(display, self.character.x, self.character.y);
 	}
 
 	/// The player will immediately face `West`
 	/// They will then accelerate at a constant rate in that direction.
 	pub fn start_moving_left(&mut self) {
+		if self.is_dodging() { return; }
 		self.character.set_facing(sprite::Facing::West);
 		self.character.accel_x = -1;
 	}
@@ -306,21 +484,25 @@ impl Player {
 	/// The player will immediately face `East`
 	/// They will then accelerate at a constant rate in that direction.
 	pub fn start_moving_right(&mut self) {
+		if self.is_dodging() { return; }
 		self.character.set_facing(sprite::Facing::East);
 		self.character.accel_x = 1;
 	}
 
 	pub fn start_moving_up(&mut self) {
+		if self.is_dodging() { return; }
 		self.character.accel_y = -1;
 	}
 
 	pub fn start_moving_down(&mut self) {
+		if self.is_dodging() { return; }
 		self.character.accel_y = 1;
 	}
 
 	/// The player will immediately cease acceleration.
 	/// They will still be facing the same direction as before this call.
 	pub fn stop_moving_horizontally(&mut self, hard_stop: bool) {
+		if self.is_dodging() { return; }
 		self.character.accel_x = 0;
 		if hard_stop {
 			self.character.velocity_x = units::Velocity(0.0);
@@ -328,49 +510,272 @@ impl Player {
 	}
 
 	pub fn stop_moving_vertically(&mut self, hard_stop: bool) {
+		if self.is_dodging() { return; }
 		self.character.accel_y = 0;
 		if hard_stop {
 			self.character.velocity_y = units::Velocity(0.0);
 		}
 	}
 
-	pub fn give_bat(&mut self) {
-		self.cricket_bat = true;
+	pub fn start_sprinting(&mut self) {
+		self.sprint_held = true;
+	}
+
+	pub fn stop_sprinting(&mut self) {
+		self.sprint_held = false;
 	}
 
-	pub fn take_bat(&mut self) {
-		self.cricket_bat = false;
+	/// Whether the player actually sprinted this update.
+	pub fn is_sprinting(&self) -> bool {
+		self.sprinting
+	}
+
+	pub fn is_exhausted(&self) -> bool {
+		self.exhausted_timer > 0
+	}
+
+	pub fn refill_stamina(&mut self) {
+		self.stamina = MAX_STAMINA;
+		self.exhausted_timer = 0;
+	}
+
+	/// Sprinting costs no stamina for a while.
+	pub fn boost_stamina(&mut self) {
+		self.refill_stamina();
+		self.stamina_boost_timer = STAMINA_BOOST_FRAMES;
+	}
+
+	pub fn is_stamina_boosted(&self) -> bool {
+		self.stamina_boost_timer > 0
+	}
+
+	pub fn get_stamina(&self) -> f64 {
+		self.stamina
+	}
+
+	pub fn get_max_stamina(&self) -> f64 {
+		MAX_STAMINA
+	}
+
+	/// The direction the player is aiming in: straight up or down while only
+	/// moving vertically, otherwise the way they are facing.
+	pub fn aim_direction(&self) -> (f64, f64) {
+		if self.character.accel_x == 0 && self.character.accel_y != 0 {
+			return (0.0, self.character.accel_y as f64);
+		}
+		match self.character.movement {
+			(_, sprite::Facing::West) => (-1.0, 0.0),
+			(_, sprite::Facing::East) => (1.0, 0.0)
+		}
+	}
+
+	/// Arms the player with `weapon`. Picking up a weapon they already carry
+	/// tops it up instead.
+	pub fn give_weapon(&mut self, weapon: weapons::Weapon) {
+		match self.weapons.iter().position(|held| held.get_type() == weapon.get_type()) {
+			Some(index) => {
+				self.weapons.get_mut(index).unwrap().refill();
+				self.current_weapon = index;
+			},
+			None => {
+				self.weapons.push(weapon);
+				self.current_weapon = self.weapons.len() - 1;
+			}
+		}
+	}
+
+	/// Tops up every gun carried.
+	pub fn add_ammo(&mut self, ammo: u32) {
+		for weapon in self.weapons.iter_mut().filter(|weapon| !weapon.is_melee()) {
+			weapon.add_ammo(ammo);
+		}
+	}
+
+	/// Cycles to the next weapon carried.
+	pub fn switch_weapon(&mut self) {
+		if !self.weapons.is_empty() {
+			self.current_weapon = (self.current_weapon + 1) % self.weapons.len();
+		}
+	}
+
+	pub fn get_weapon(&self) -> Option<&weapons::Weapon> {
+		self.weapons.get(self.current_weapon)
+	}
+
+	pub fn get_weapon_mut(&mut self) -> Option<&mut weapons::Weapon> {
+		self.weapons.get_mut(self.current_weapon)
+	}
+
+	/// Wears down the current weapon, throwing it away once it breaks.
+	pub fn wear_weapon(&mut self) {
+		let broken = match self.weapons.get_mut(self.current_weapon) {
+			Some(weapon) => { weapon.wear(); weapon.is_broken() },
+			None         => false
+		};
+		if broken {
+			self.weapons.remove(self.current_weapon);
+			self.current_weapon = 0;
+		}
+	}
+
+	/// Vehicle parts the player is lugging around, each one slows them down.
+	pub fn get_parts(&self) -> &[Box<dyn vehicle::Part>] {
+		&self.parts
+	}
+
+	pub fn carry_part(&mut self, part: Box<dyn vehicle::Part>) {
+		self.parts.push(part);
+	}
+
+	/// Lets go of the last part picked up.
+	pub fn drop_part(&mut self) -> Option<Box<dyn vehicle::Part>> {
+		self.parts.pop()
 	}
 
 	pub fn has_bat(&self) -> bool {
-		self.cricket_bat
+		match self.get_weapon() {
+			Some(weapon) => weapon.get_type() == weapons::CRICKET_BAT,
+			None         => false
+		}
+	}
+
+	/// Damage dealt to a zombie run into with the bat out, or mid-teleport,
+	/// which hits as hard as the bat does.
+	pub fn contact_damage(&self) -> u32 {
+		match self.get_weapon() {
+			Some(weapon) if weapon.is_melee() => weapon.get_damage(),
+			_ => {
+				let (damage, _, _, _) = weapons::stats(weapons::CRICKET_BAT);
+				damage
+			}
+		}
 	}
 
 	pub fn start_teleport_timer(&mut self) {
-		self.teleport_timer = 20;
+		self.character.effects.apply(status::Effect::Teleporting, TELEPORT_FRAMES);
 	}
 
-	pub fn is_teleporting(&mut self) -> bool {
-		self.teleport_timer > 0
+	pub fn is_teleporting(&self) -> bool {
+		self.character.effects.has(status::Effect::Teleporting)
 	}
 
-	pub fn start_immunity(&mut self) {
-		self.immunity_timer = 60;
+	pub fn start_immunity(&mut self, frames: i32) {
+		self.character.health.start_invulnerability(frames);
 	}
 
-	pub fn is_immune(&mut self) -> bool {
-		self.immunity_timer > 0
+	/// Rolls in the direction the player is moving (or facing when standing
+	/// still), briefly immune to everything. Costs stamina and has a cooldown.
+	pub fn start_dodge(&mut self) {
+		if self.is_dodging() || self.dodge_cooldown > 0 || self.is_exhausted() || self.stamina < DODGE_STAMINA {
+			return;
+		}
+
+		self.launch(DODGE_FRAMES);
+		self.stamina -= DODGE_STAMINA;
+		self.dodge_cooldown = DODGE_COOLDOWN;
+		self.start_immunity(DODGE_IMMUNITY_FRAMES);
+	}
+
+	/// Sends the player flying the way they were heading, e.g. off a spring.
+	pub fn fling(&mut self) {
+		self.launch(FLING_FRAMES);
+		self.start_immunity(FLING_FRAMES);
+	}
+
+	/// Rolls at full speed in the direction the player is moving (or facing
+	/// when standing still) for `frames` updates.
+	fn launch(&mut self, frames: i32) {
+		let (dx, dy) = if self.character.accel_x != 0 || self.character.accel_y != 0 {
+			(self.character.accel_x as f64, self.character.accel_y as f64)
+		} else {
+			self.aim_direction()
+		};
+		let length = (dx * dx + dy * dy).sqrt();
+		let units::Velocity(speed) = DODGE_VELOCITY;
+		self.character.velocity_x = units::Velocity(speed * dx / length);
+		self.character.velocity_y = units::Velocity(speed * dy / length);
+		self.character.accel_x = dx as i32;
+		self.character.accel_y = dy as i32;
+		self.dodge_timer = frames;
+	}
+
+	pub fn is_dodging(&self) -> bool {
+		self.dodge_timer > 0
+	}
+
+	pub fn is_immune(&self) -> bool {
+		self.character.health.is_invulnerable()
+	}
+
+	/// Hurts the player, who becomes immune for a short while afterwards.
+	/// On a killing blow the player's death animation is started.
+	pub fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		let result = self.character.take_damage(&damage);
+		if result == health::DamageResult::Killed {
+			self.character.kill_character();
+		}
+		result
+	}
+
+	/// Whether the player has been knocked down and is waiting on their
+	/// partner to come and pick them up.
+	pub fn is_down(&self) -> bool {
+		self.character.health.is_dead()
+	}
+
+	/// Gets a downed player back on their feet with `amount` health, briefly
+	/// immune so they aren't knocked straight back down.
+	pub fn revive(&mut self, amount: u32) {
+		self.character.health.revive(amount);
+		self.character.revive_character();
+		self.start_immunity(IMMUNITY_FRAMES);
+	}
+
+	pub fn heal(&mut self, amount: u32) {
+		self.character.health.heal(amount);
+	}
+
+	pub fn raise_max_health(&mut self, amount: u32) {
+		self.character.health.raise_max(amount);
 	}
 
-	pub fn hit_player(&mut self) {
-		self.health = self.health - 1;
+	/// Carries a max health earned on earlier levels over, at full health.
+	pub fn set_max_health(&mut self, max: u32) {
+		self.character.health.set_max(max);
+	}
+
+	pub fn get_health(&self) -> u32 {
+		self.character.health.get_current()
+	}
+
+	pub fn get_max_health(&self) -> u32 {
+		self.character.health.get_max()
+	}
+
+	/// Where a zombie standing at `x`, `y` would head to follow the player's
+	/// scent. Without a trail to pick up it heads straight for the player, as
+	/// zombies did before scent trails, unless the trail has been broken by
+	/// hiding or teleporting, in which case this is `None`.
+	pub fn track_scent(&self, map: &map::Map, x: units::Game, y: units::Game) -> Option<(units::Game, units::Game)> {
+		if self.is_hidden() {
+			return None;
+		}
+		match self.scent.freshest_near(map, x, y, scent::SMELL_RANGE) {
+			Some(crumb) => Some(crumb.position()),
+			None if self.trail_lost_timer > 0 => None,
+			None => Some((self.character.map_center_x(), self.character.map_center_y()))
+		}
 	}
 
-	pub fn get_health(&mut self) -> u32 {
-		self.health
+	/// Wipes the scent trail so zombies lose track of the player for a while.
+	pub fn break_trail(&mut self) {
+		self.scent.clear();
+		self.trail_lost_timer = TRAIL_LOST_FRAMES;
 	}
 
-	pub fn get_follow_coords(&self) -> (units::Game, units::Game) {
-		(self.follow_x, self.follow_y)
+	/// Whether the player has kept still against a wall long enough that
+	/// zombies can't track them.
+	pub fn is_hidden(&self) -> bool {
+		self.hide_timer >= HIDE_FRAMES
 	}
 }
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
 use game::collisions::Rectangle;
+use game::datafile;
 use game::sprite;
 use game::graphics;
 use game::map;
+use game::weapons;
 
 use game::units;
 
@@ -25,21 +27,100 @@ const BAD_FRAME: units::Tile = units::Tile(5);
 const TELEPORT_FRAME: units::Tile = units::Tile(6);
 const TELEPORT_ANIMATION_FRAME: units::Tile = units::Tile(0);
 
+// item icons (32x16) on the item sheet
+const ENERGY_DRINK_ITEM: (units::HalfTile, units::HalfTile) = (units::HalfTile(4), units::HalfTile(0));
+const ADRENALINE_ITEM: (units::HalfTile, units::HalfTile) = (units::HalfTile(6), units::HalfTile(0));
+
+// hearts (16x16) on the heart sheet
+const HEART_OFFSET: (units::Game, units::Game) = (units::Game(0.0), units::Game(0.0));
+const HEART_CONTAINER_OFFSET: (units::Game, units::Game) = (units::Game(21.0), units::Game(0.0));
+
+// weapon icons (16x16) on the weapon icon sheet
+const PISTOL_ICON: units::HalfTile = units::HalfTile(2);
+const MACHINE_GUN_ICON: units::HalfTile = units::HalfTile(4);
+
+// trap kits show an open bear trap
+const TRAP_KIT_FRAME: units::Tile = units::Tile(0);
+
+// zombie drops, ammo is a bullet (16x16) and time bonuses an item (32x16)
+const AMMO_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(8), units::HalfTile(2));
+const TIME_BONUS_ITEM: (units::HalfTile, units::HalfTile) = (units::HalfTile(2), units::HalfTile(2));
+
+pub static POWERUP_DATA: &str = "assets/powerups.txt";
+
+/// The powerup kind data files refer to by `name`. Kind 0 is nothing.
+pub fn kind_named(name: &str) -> Option<u32> {
+	match name {
+		"nothing"         => Some(0),
+		"cricket-bat"     => Some(1),
+		"kill-zombie"     => Some(2),
+		"wipe-out"        => Some(3),
+		"freeze"          => Some(4),
+		"teleport"        => Some(5),
+		"nuke"            => Some(6),
+		"energy-drink"    => Some(7),
+		"adrenaline"      => Some(8),
+		"pistol"          => Some(9),
+		"machine-gun"     => Some(10),
+		"heart"           => Some(11),
+		"heart-container" => Some(12),
+		"trap-kit"        => Some(13),
+		"ammo"            => Some(14),
+		"time-bonus"      => Some(15),
+		_                 => None
+	}
+}
+
+/// Reads how likely (out of 100) each kind of powerup is to be cursed
+/// whenever curses are rolled from the data file at `path`.
+pub fn load_debuff_odds(path: &str) -> Vec<(i32, u32)> {
+	let mut odds: Vec<(i32, u32)> = Vec::new();
+	datafile::read_lines(path, "powerups", |line| {
+		if line.keyword != "debuff" {
+			line.error("unknown keyword");
+		}
+		if line.rest.len() != 2 {
+			line.error("expected a powerup kind and odds");
+		}
+		let kind = match kind_named(line.rest[0]) {
+			Some(kind) if kind > 0 => kind as i32,
+			_                      => line.error("unknown powerup")
+		};
+		match line.rest[1].parse::<u32>() {
+			Ok(chance) if chance <= 100 => { odds.push((kind, chance)); },
+			_                           => line.error("odds should be between 0 and 100")
+		}
+	});
+	odds
+}
+
+/// How likely (out of 100) powerups of `kind` are to be cursed, kinds
+/// missing from `odds` never are.
+pub fn debuff_odds(odds: &[(i32, u32)], kind: i32) -> u32 {
+	match odds.iter().find(|&&(odds_kind, _)| odds_kind == kind) {
+		Some(&(_, chance)) => chance,
+		None               => 0
+	}
+}
+
 // Animation frames 
 const WIPEOUT_ANIMATION_FRAME: units::Tile = units::Tile(1);
 const NUKE_ANIMATION_FRAME: units::Tile = units::Tile(0);
 
 pub trait Powerup {
 	fn draw(&self, display: &mut graphics::Graphics);
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game);
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map);
 	fn damage_rectangle(&self) -> Rectangle;
 	fn get_type(&self) -> i32;
-	fn toggle_debuff(&mut self);
+	fn set_debuff(&mut self, debuff: bool);
 	fn is_debuff(&self) -> bool;
+	fn auto_trigger(&self) -> bool;
 	fn is_finished(&mut self) -> bool;
 	fn set_timer(&mut self);
 	fn get_map_x(&self) -> units::Game;
 	fn get_map_y(&self) -> units::Game;
+	fn move_by(&mut self, dx: units::Game, dy: units::Game);
 }
 
 pub struct CricketBat {
@@ -72,6 +153,48 @@ pub struct Teleport {
 	is_debuff: bool
 }
 
+pub struct EnergyDrink {
+	character: Character, 
+	is_debuff: bool
+}
+
+pub struct Adrenaline {
+	character: Character, 
+	is_debuff: bool
+}
+
+pub struct WeaponPickup {
+	character: Character, 
+	weapon: u32,
+	is_debuff: bool
+}
+
+pub struct Heart {
+	character: Character, 
+	is_debuff: bool
+}
+
+pub struct HeartContainer {
+	character: Character, 
+	is_debuff: bool
+}
+
+/// A kit for one trap or barricade the player can put down later.
+pub struct TrapKit {
+	character: Character, 
+	is_debuff: bool
+}
+
+pub struct Ammo {
+	character: Character, 
+	is_debuff: bool
+}
+
+pub struct TimeBonus {
+	character: Character, 
+	is_debuff: bool
+}
+
 pub struct Nuke {
 	character: Character, 
 	alternate_sprites: HashMap<MotionTup, Box<dyn sprite::Updatable<units::Game>>>,
@@ -125,6 +248,10 @@ impl Powerup for CricketBat {
 		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
 	}
 
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
 	#[allow(unused_variables)]
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		
@@ -134,14 +261,19 @@ impl Powerup for CricketBat {
 		self.character.damage_rectangle()
 	}
 
-	fn toggle_debuff(&mut self) {
-		self.is_debuff = !self.is_debuff;
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
 	}
 
 	fn is_debuff(&self) -> bool {
 		self.is_debuff
 	}
 
+	/// Used the moment it is touched rather than stored in the inventory.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
 	fn get_type(&self) -> i32 {
 		1
 	}
@@ -161,6 +293,11 @@ impl Powerup for CricketBat {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
 }
 
 impl KillZombie {
@@ -209,6 +346,10 @@ impl Powerup for KillZombie {
 		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
 	}
 
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
 	#[allow(unused_variables)]
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 
@@ -218,14 +359,18 @@ impl Powerup for KillZombie {
 		self.character.damage_rectangle()
 	}
 
-	fn toggle_debuff(&mut self) {
-		self.is_debuff = !self.is_debuff;
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
 	}
 
 	fn is_debuff(&self) -> bool {
 		self.is_debuff
 	}
 
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
 	fn get_type(&self) -> i32 {
 		2
 	}
@@ -248,6 +393,11 @@ impl Powerup for KillZombie {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
 }
 
 impl WipeOut {
@@ -314,6 +464,10 @@ impl Powerup for WipeOut {
 		}
 	}
 
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
 	#[allow(unused_variables)]
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		self.animation_sprite.get_mut(0).unwrap().update(elapsed_time);
@@ -323,14 +477,18 @@ impl Powerup for WipeOut {
 		self.character.damage_rectangle()
 	}
 
-	fn toggle_debuff(&mut self) {
-		self.is_debuff = !self.is_debuff;
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
 	}
 
 	fn is_debuff(&self) -> bool {
 		self.is_debuff
 	}
 
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
 	fn get_type(&self) -> i32 {
 		3
 	}
@@ -353,6 +511,11 @@ impl Powerup for WipeOut {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
 }
 
 impl Freeze {
@@ -400,6 +563,10 @@ impl Powerup for Freeze {
 		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
 	}
 
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
 	#[allow(unused_variables)]
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		
@@ -409,14 +576,18 @@ impl Powerup for Freeze {
 		self.character.damage_rectangle()
 	}
 
-	fn toggle_debuff(&mut self) {
-		self.is_debuff = !self.is_debuff;
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
 	}
 
 	fn is_debuff(&self) -> bool {
 		self.is_debuff
 	}
 
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
 	fn get_type(&self) -> i32 {
 		4
 	}
@@ -436,6 +607,11 @@ impl Powerup for Freeze {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
 }
 
 impl Teleport {
@@ -502,6 +678,10 @@ impl Powerup for Teleport {
 		}
 	}
 
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
 	#[allow(unused_variables)]
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		self.animation_sprite.get_mut(0).unwrap().update(elapsed_time);
@@ -511,14 +691,18 @@ impl Powerup for Teleport {
 		self.character.damage_rectangle()
 	}
 
-	fn toggle_debuff(&mut self) {
-		self.is_debuff = !self.is_debuff;
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
 	}
 
 	fn is_debuff(&self) -> bool {
 		self.is_debuff
 	}
 
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
 	fn get_type(&self) -> i32 {
 		5
 	}
@@ -541,6 +725,11 @@ impl Powerup for Teleport {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
 }
 
 impl Nuke {
@@ -629,6 +818,10 @@ impl Powerup for Nuke {
 		}
 	}
 
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
 	#[allow(unused_variables)]
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
 		self.animation_sprite.get_mut(0).unwrap().update(elapsed_time);
@@ -638,14 +831,18 @@ impl Powerup for Nuke {
 		self.character.damage_rectangle()
 	}
 
-	fn toggle_debuff(&mut self) {
-		self.is_debuff = !self.is_debuff;
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
 	}
 
 	fn is_debuff(&self) -> bool {
 		self.is_debuff
 	}
 
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
 	fn get_type(&self) -> i32 {
 		6
 	}
@@ -668,4 +865,765 @@ impl Powerup for Nuke {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl EnergyDrink {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> EnergyDrink {
+
+		let mut new_powerup = EnergyDrink { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/ItemImage.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			ENERGY_DRINK_ITEM,
+			(units::HalfTile(2), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for EnergyDrink {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
+	fn get_type(&self) -> i32 {
+		7
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl Adrenaline {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Adrenaline {
+
+		let mut new_powerup = Adrenaline { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/ItemImage.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			ADRENALINE_ITEM,
+			(units::HalfTile(2), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for Adrenaline {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	fn auto_trigger(&self) -> bool {
+		false
+	}
+
+	fn get_type(&self) -> i32 {
+		8
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl WeaponPickup {
+	/// A pickup for one of the `weapons` kinds.
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game, weapon: u32) -> WeaponPickup {
+
+		let mut new_powerup = WeaponPickup { 
+			character: common::Character::new(x, y), 
+			weapon,
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/ArmsImage.bmp".to_string();
+		let motion_frame = if self.weapon == weapons::PISTOL { PISTOL_ICON } else { MACHINE_GUN_ICON };
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			(motion_frame, units::HalfTile(0)),
+			(units::HalfTile(1), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for WeaponPickup {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	/// Weapons go straight into the player's hands.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
+	fn get_type(&self) -> i32 {
+		if self.weapon == weapons::PISTOL { 9 } else { 10 }
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl Heart {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Heart {
+
+		let mut new_powerup = Heart { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/heart.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			HEART_OFFSET,
+			(units::HalfTile(1), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for Heart {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	/// Health is picked up straight away.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
+	fn get_type(&self) -> i32 {
+		11
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl HeartContainer {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> HeartContainer {
+
+		let mut new_powerup = HeartContainer { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/heart.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			HEART_CONTAINER_OFFSET,
+			(units::HalfTile(1), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for HeartContainer {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	/// Raises max health the moment it is picked up.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
+	fn get_type(&self) -> i32 {
+		12
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl TrapKit {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> TrapKit {
+
+		let mut new_powerup = TrapKit { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/traps.bmp".to_string();
+		let motion_frame = TRAP_KIT_FRAME;
+
+		let facing_frame = units::Tile(0);
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			(motion_frame, facing_frame),
+			(units::Tile(1), units::Tile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for TrapKit {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	/// Kits go straight into the trap bag rather than an inventory slot.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
+	fn get_type(&self) -> i32 {
+		13
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl Ammo {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Ammo {
+
+		let mut new_powerup = Ammo { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/Bullet.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			AMMO_FRAME,
+			(units::HalfTile(1), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for Ammo {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	/// Tops up every gun the moment it is touched.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
+	fn get_type(&self) -> i32 {
+		14
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
+}
+
+impl TimeBonus {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> TimeBonus {
+
+		let mut new_powerup = TimeBonus { 
+			character: common::Character::new(x, y), 
+			is_debuff: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_powerup.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_powerup
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/ItemImage.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			TIME_BONUS_ITEM,
+			(units::HalfTile(2), units::HalfTile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Powerup for TimeBonus {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn set_debuff(&mut self, debuff: bool) {
+		self.is_debuff = debuff;
+	}
+
+	fn is_debuff(&self) -> bool {
+		self.is_debuff
+	}
+
+	/// Adds to the level timer the moment it is touched.
+	fn auto_trigger(&self) -> bool {
+		true
+	}
+
+	fn get_type(&self) -> i32 {
+		15
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn move_by(&mut self, dx: units::Game, dy: units::Game) {
+		self.character.map_x = self.character.map_x + dx;
+		self.character.map_y = self.character.map_y + dy;
+	}
 }
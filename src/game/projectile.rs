@@ -0,0 +1,163 @@
+use game;
+use game::collisions::Rectangle;
+use game::common;
+use game::graphics;
+use game::health;
+use game::map;
+use game::sprite;
+
+use game::units;
+use game::units::{AsGame,AsTile};
+
+// sprite sheet and frames (16x16) for the different shots
+static PROJECTILE_SHEET: &str = "assets/base/Bullet.bmp";
+pub static BULLET_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(8), units::HalfTile(2));
+pub static SPIT_FRAME:   (units::HalfTile, units::HalfTile) = (units::HalfTile(0), units::HalfTile(8));
+
+// shots are a half tile square
+static PROJECTILE_SIZE: units::HalfTile = units::HalfTile(1);
+
+static KNOCKBACK: f64 = 0.1;
+
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum Owner {
+	Player,
+	Zombie
+}
+
+/// How a projectile flies and what it does, everything but where it starts.
+#[derive(Clone,Copy)]
+pub struct Shot {
+	/// Which way it heads, it does not need to be normalised.
+	pub direction: (f64, f64),
+	pub speed:     units::Velocity,
+	pub damage:    health::Damage,
+	pub owner:     Owner,
+	/// How many updates it lasts before fizzling out.
+	pub lifetime:  i32,
+	/// Where its sprite is on the projectile sheet.
+	pub frame:     (units::HalfTile, units::HalfTile)
+}
+
+/// Anything fired or thrown across the map.
+///
+/// A projectile travels in a straight line until it hits a wall, hits
+/// something it can hurt or runs out of `lifetime` (in updates). Shots
+/// never hurt whoever fired them: the player's bullets only hit zombies
+/// and zombie attacks only hit the player.
+pub struct Projectile {
+	sprite:     Box<dyn sprite::Updatable<units::Game>>,
+	map_x:      units::Game,
+	map_y:      units::Game,
+	velocity_x: units::Velocity,
+	velocity_y: units::Velocity,
+	lifetime:   i32,
+	owner:      Owner,
+	damage:     health::Damage,
+	finished:   bool
+}
+
+impl Projectile {
+	/// Fires `shot` from `x`, `y`, the projectile being centred there.
+	pub fn new(graphics: &mut graphics::Graphics, x: units::Game, y: units::Game, shot: Shot) -> Projectile {
+		let (dx, dy) = shot.direction;
+		let length = (dx * dx + dy * dy).sqrt();
+		let (dx, dy) = if length == 0.0 { (1.0, 0.0) } else { (dx / length, dy / length) };
+		let units::Velocity(speed) = shot.speed;
+
+		let mut damage = shot.damage;
+		damage.knockback = (units::Velocity(dx * KNOCKBACK), units::Velocity(dy * KNOCKBACK));
+
+		let sprite = Box::new( sprite::Sprite::new(
+			graphics,
+			shot.frame,
+			(PROJECTILE_SIZE, PROJECTILE_SIZE),
+			PROJECTILE_SHEET.to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		let units::Game(size) = PROJECTILE_SIZE.to_game();
+		Projectile {
+			sprite,
+			map_x:      x - units::Game(size / 2.0),
+			map_y:      y - units::Game(size / 2.0),
+			velocity_x: units::Velocity(dx * speed),
+			velocity_y: units::Velocity(dy * speed),
+			lifetime:   shot.lifetime,
+			owner:      shot.owner,
+			damage,
+			finished:   false
+		}
+	}
+
+	/// The direction to fire in to travel from `from` to `to`.
+	pub fn direction_to(from: (units::Game, units::Game), to: (units::Game, units::Game)) -> (f64, f64) {
+		let ((units::Game(fx), units::Game(fy)), (units::Game(tx), units::Game(ty))) = (from, to);
+		(tx - fx, ty - fy)
+	}
+
+	/// Moves the projectile, which stops when it hits a wall or leaves the map.
+	pub fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		if self.finished {
+			return;
+		}
+
+		self.lifetime -= 1;
+		self.map_x = self.map_x + self.velocity_x * elapsed_time;
+		self.map_y = self.map_y + self.velocity_y * elapsed_time;
+		self.sprite.update(elapsed_time);
+
+		let rectangle = self.damage_rectangle();
+		let units::Tile(last_col) = rectangle.right().to_tile();
+		let units::Tile(last_row) = rectangle.bottom().to_tile();
+		let (units::Tile(width), units::Tile(height)) = (game::LEVEL_WIDTH, game::LEVEL_HEIGHT);
+		if self.lifetime <= 0 || rectangle.left() < units::Game(0.0) || rectangle.top() < units::Game(0.0)
+		   || last_col >= width || last_row >= height {
+			self.finished = true;
+			return;
+		}
+
+		for tile in map.get_colliding_tiles(&rectangle).iter() {
+			if tile.tile_type == map::TileType::Wall {
+				self.finished = true;
+				return;
+			}
+		}
+	}
+
+	pub fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.map_x % common::SCREEN_CORRECTION;
+		let correction_y = self.map_y % common::SCREEN_CORRECTION;
+		self.sprite.draw(display, (correction_x, correction_y));
+	}
+
+	pub fn damage_rectangle(&self) -> Rectangle {
+		Rectangle {
+			x: self.map_x,
+			y: self.map_y,
+			width: PROJECTILE_SIZE.to_game(),
+			height: PROJECTILE_SIZE.to_game()
+		}
+	}
+
+	/// Spends the projectile on whatever it just hit and returns the damage to deal.
+	pub fn hit(&mut self) -> health::Damage {
+		self.finished = true;
+		self.damage
+	}
+
+	pub fn is_finished(&self) -> bool {
+		self.finished
+	}
+
+	pub fn get_owner(&self) -> Owner {
+		self.owner
+	}
+
+	pub fn get_map_x(&self) -> units::Game {
+		self.map_x
+	}
+
+	pub fn get_map_y(&self) -> units::Game {
+		self.map_y
+	}
+}
@@ -0,0 +1,90 @@
+use game::map;
+
+use game::units;
+use game::units::{AsGame,AsTile};
+
+// how long a breadcrumb lingers before it has fully faded (in updates)
+pub static SCENT_FRAMES:  i32         = 900;
+// how far away a zombie can pick up the trail
+pub static SMELL_RANGE:   units::Tile = units::Tile(10);
+static MAX_BREADCRUMBS:   usize       = 120;
+
+/// A single tile the player has walked through.
+pub struct Breadcrumb {
+	pub col:      u32,
+	pub row:      u32,
+	pub strength: i32
+}
+
+impl Breadcrumb {
+	/// The centre of the breadcrumb's tile.
+	pub fn position(&self) -> (units::Game, units::Game) {
+		(units::Tile(self.col).to_game() + units::HalfTile(1).to_game(),
+		 units::Tile(self.row).to_game() + units::HalfTile(1).to_game())
+	}
+}
+
+/// A decaying trail of breadcrumbs left on the tile grid.
+///
+/// A new breadcrumb is dropped whenever the owner steps onto a new tile and
+/// the one underfoot is kept fresh while they stand still. Zombies head for
+/// the freshest breadcrumb they can see, so they follow the trail around
+/// corners rather than walking into the wall between them and the player.
+pub struct ScentTrail {
+	crumbs: Vec<Breadcrumb>
+}
+
+impl Default for ScentTrail {
+	fn default() -> ScentTrail {
+		ScentTrail::new()
+	}
+}
+
+impl ScentTrail {
+	pub fn new() -> ScentTrail {
+		ScentTrail { crumbs: Vec::new() }
+	}
+
+	/// Fades the trail by one update and marks the tile at `x`, `y`.
+	pub fn update(&mut self, x: units::Game, y: units::Game) {
+		for crumb in self.crumbs.iter_mut() {
+			crumb.strength -= 1;
+		}
+		self.crumbs.retain(|crumb| crumb.strength > 0);
+
+		let (units::Tile(col), units::Tile(row)) = (x.to_tile(), y.to_tile());
+		let standing_still = match self.crumbs.last() {
+			Some(crumb) => crumb.col == col && crumb.row == row,
+			None        => false
+		};
+
+		if standing_still {
+			self.crumbs.last_mut().unwrap().strength = SCENT_FRAMES;
+		} else {
+			self.crumbs.push(Breadcrumb { col, row, strength: SCENT_FRAMES });
+			if self.crumbs.len() > MAX_BREADCRUMBS {
+				self.crumbs.remove(0);
+			}
+		}
+	}
+
+	/// Wipes the trail, e.g. when the owner teleports.
+	pub fn clear(&mut self) {
+		self.crumbs.clear();
+	}
+
+	/// The freshest breadcrumb within `range` of `x`, `y` that is not behind a wall.
+	pub fn freshest_near(&self, map: &map::Map, x: units::Game, y: units::Game, range: units::Tile) -> Option<&Breadcrumb> {
+		let units::Game(range) = range.to_game();
+		self.crumbs.iter().rev().find(|crumb| {
+			let (crumb_x, crumb_y) = crumb.position();
+			let units::Game(xs) = (crumb_x - x) * (crumb_x - x);
+			let units::Game(ys) = (crumb_y - y) * (crumb_y - y);
+			(xs + ys).sqrt() <= range && map.line_of_sight((x, y), (crumb_x, crumb_y))
+		})
+	}
+
+	pub fn get_breadcrumbs(&self) -> &Vec<Breadcrumb> {
+		&self.crumbs
+	}
+}
@@ -0,0 +1,220 @@
+use game::graphics;
+
+use game::units;
+
+use sdl2::rect;
+
+// how much slow and haste change movement speed
+static SLOW_FACTOR:    f64 = 0.5;
+static HASTE_FACTOR:   f64 = 1.5;
+
+// burning hurts once a second for every stack
+static BURN_INTERVAL:   i32 = 60;
+static MAX_BURN_STACKS: u32 = 3;
+
+// effect pips drawn above whoever is affected (in pixels)
+static ICON_SIZE:    u32 = 4;
+static ICON_SPACING: f64 = 6.0;
+static ICON_OFFSET:  f64 = -6.0;
+
+#[derive(PartialEq,Eq,Clone,Copy,Debug)]
+pub enum Effect {
+	/// Can't move at all, e.g. from the Freeze powerup.
+	Frozen,
+	/// Mid-teleport, zombies touched are destroyed.
+	Teleporting,
+	Slow,
+	/// Takes damage at regular intervals, stacks up to three times.
+	Burning,
+	/// Knocked senseless for a moment, can't move.
+	Stunned,
+	Haste,
+	/// Moves the opposite way to where it wants to go.
+	Confused,
+	/// Can only see a little way around them.
+	Blinded,
+	/// Draws every zombie on the map towards them.
+	Magnetized
+}
+
+/// How applying an effect that is already active combines with it.
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum Stacking {
+	/// Keeps whichever of the two durations is longer.
+	Refresh,
+	/// Adds the new duration onto what is left.
+	Extend,
+	/// Adds a stack, up to a limit, and refreshes the duration.
+	Intensify(u32)
+}
+
+/// Something that happened to an effect during an update.
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum Event {
+	/// A periodic effect went off with this many stacks.
+	Tick(Effect, u32),
+	Expired(Effect)
+}
+
+impl Effect {
+	pub fn stacking(&self) -> Stacking {
+		match *self {
+			Effect::Burning => Stacking::Intensify(MAX_BURN_STACKS),
+			Effect::Haste   => Stacking::Extend,
+			_               => Stacking::Refresh
+		}
+	}
+
+	/// How often the effect ticks (in updates), zero for effects that don't.
+	pub fn tick_interval(&self) -> i32 {
+		match *self {
+			Effect::Burning => BURN_INTERVAL,
+			_               => 0
+		}
+	}
+
+	pub fn color(&self) -> (u8, u8, u8) {
+		match *self {
+			Effect::Frozen      => (120, 200, 255),
+			Effect::Teleporting => (200, 120, 255),
+			Effect::Slow        => (90, 90, 200),
+			Effect::Burning     => (255, 120, 0),
+			Effect::Stunned     => (255, 255, 120),
+			Effect::Haste       => (40, 220, 120),
+			Effect::Confused    => (240, 80, 200),
+			Effect::Blinded     => (60, 60, 60),
+			Effect::Magnetized  => (200, 40, 40)
+		}
+	}
+}
+
+/// A single timed effect.
+pub struct StatusEffect {
+	pub kind:      Effect,
+	pub remaining: i32,
+	pub stacks:    u32,
+	age:           i32
+}
+
+/// Every timed buff and debuff on a player or zombie.
+///
+/// Effects count down once per update. `update` reports any ticks and
+/// expiries, so the owner (or the game, for anything that needs to know
+/// about the wider world, like damage) can react to them.
+pub struct StatusEffects {
+	effects: Vec<StatusEffect>
+}
+
+impl Default for StatusEffects {
+	fn default() -> StatusEffects {
+		StatusEffects::new()
+	}
+}
+
+impl StatusEffects {
+	pub fn new() -> StatusEffects {
+		StatusEffects { effects: Vec::new() }
+	}
+
+	/// Applies `kind` for `frames` updates, combining it with an active
+	/// effect of the same kind by the effect's stacking rules.
+	pub fn apply(&mut self, kind: Effect, frames: i32) {
+		if let Some(effect) = self.effects.iter_mut().find(|effect| effect.kind == kind) {
+			match kind.stacking() {
+				Stacking::Refresh => {
+					if frames > effect.remaining {
+						effect.remaining = frames;
+					}
+				},
+				Stacking::Extend => {
+					effect.remaining += frames;
+				},
+				Stacking::Intensify(max_stacks) => {
+					if effect.stacks < max_stacks {
+						effect.stacks += 1;
+					}
+					if frames > effect.remaining {
+						effect.remaining = frames;
+					}
+				}
+			}
+			return;
+		}
+		self.effects.push(StatusEffect { kind, remaining: frames, stacks: 1, age: 0 });
+	}
+
+	/// Counts every effect down by one update.
+	pub fn update(&mut self) -> Vec<Event> {
+		let mut events = Vec::new();
+		for effect in self.effects.iter_mut() {
+			effect.remaining -= 1;
+			effect.age += 1;
+			let interval = effect.kind.tick_interval();
+			if interval > 0 && effect.age % interval == 0 {
+				events.push(Event::Tick(effect.kind, effect.stacks));
+			}
+			if effect.remaining <= 0 {
+				events.push(Event::Expired(effect.kind));
+			}
+		}
+		self.effects.retain(|effect| effect.remaining > 0);
+		events
+	}
+
+	pub fn remove(&mut self, kind: Effect) {
+		self.effects.retain(|effect| effect.kind != kind);
+	}
+
+	pub fn clear(&mut self) {
+		self.effects.clear();
+	}
+
+	pub fn has(&self, kind: Effect) -> bool {
+		self.effects.iter().any(|effect| effect.kind == kind)
+	}
+
+	/// Updates left on `kind`, zero when it isn't active.
+	pub fn remaining(&self, kind: Effect) -> i32 {
+		match self.effects.iter().find(|effect| effect.kind == kind) {
+			Some(effect) => effect.remaining,
+			None         => 0
+		}
+	}
+
+	pub fn is_immobile(&self) -> bool {
+		self.has(Effect::Frozen) || self.has(Effect::Stunned)
+	}
+
+	pub fn is_confused(&self) -> bool {
+		self.has(Effect::Confused)
+	}
+
+	/// Scales movement for slow and haste. The two cancel out.
+	pub fn adjust_movement(&self, acceleration: units::Acceleration, max_velocity: units::Velocity) -> (units::Acceleration, units::Velocity) {
+		let mut factor = 1.0;
+		if self.has(Effect::Slow) {
+			factor *= SLOW_FACTOR;
+		}
+		if self.has(Effect::Haste) {
+			factor *= HASTE_FACTOR;
+		}
+		let (units::Acceleration(acceleration), units::Velocity(max_velocity)) = (acceleration, max_velocity);
+		(units::Acceleration(acceleration * factor), units::Velocity(max_velocity * factor))
+	}
+
+	/// Which way to head when trying to go `direction` (-1, 0 or 1).
+	pub fn steer(&self, direction: i32) -> i32 {
+		if self.is_confused() { -direction } else { direction }
+	}
+
+	/// Draws a coloured pip per effect above the owner, `x` and `y` being its
+	/// position on screen.
+	pub fn draw(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		let (units::Game(x), units::Game(y)) = (x, y);
+		for (i, effect) in self.effects.iter().enumerate() {
+			let icon_x = x + (i as f64 * ICON_SPACING);
+			let icon_rect = rect::Rect::new(icon_x as i32, (y + ICON_OFFSET) as i32, ICON_SIZE, ICON_SIZE);
+			display.draw_bar(1.0, 1.0, icon_rect, effect.kind.color());
+		}
+	}
+}
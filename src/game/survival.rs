@@ -0,0 +1,156 @@
+use rand;
+use rand::Rng;
+
+use game::leaderboard;
+use game::game::MAX_ENEMIES;
+
+pub static LEADERBOARD_FILE: &str = "survival_leaderboard.txt";
+
+// the run gets harder every stage (in updates)
+static STAGE_FRAMES:        i32 = 1800;
+static INITIAL_POPULATION:  (u32, u32) = (4, 8);
+
+// spawn intervals (in updates)
+static START_INTERVAL:      i32 = 300;
+static INTERVAL_STEP:       i32 = 40;
+static MIN_INTERVAL:        i32 = 60;
+
+// powerups keep turning up while there are few enough lying around
+static POWERUP_INTERVAL:    i32 = 600;
+static MAX_POWERUPS:        usize = 8;
+
+// scoring
+static SECOND_SCORE:        i32 = 10;
+static KILL_SCORE:          i32 = 50;
+static FRAMES_PER_SECOND:   i32 = 60;
+
+// chance out of 10 of a slow, crazy, random and cloud zombie at each stage,
+// stages past the end use the last mix
+static STAGE_MIX: [[u32; 4]; 5] = [
+	[7, 0, 3, 0],
+	[5, 2, 3, 0],
+	[4, 3, 2, 1],
+	[3, 4, 2, 1],
+	[2, 5, 1, 2]
+];
+
+/// Rules for endless survival: no vehicle and no level timer, just waves
+/// that keep getting bigger, faster and nastier the longer the player
+/// lasts. Scored on time survived plus kills.
+pub struct Survival {
+	frames:        i32,
+	kills:         u32,
+	spawn_timer:   i32,
+	powerup_timer: i32
+}
+
+impl Default for Survival {
+	fn default() -> Survival {
+		Survival::new()
+	}
+}
+
+impl Survival {
+	pub fn new() -> Survival {
+		Survival {
+			frames:        0,
+			kills:         0,
+			spawn_timer:   START_INTERVAL,
+			powerup_timer: POWERUP_INTERVAL
+		}
+	}
+
+	/// Resets the run and returns the kinds of zombies that should already
+	/// be roaming the map when it starts.
+	pub fn start(&mut self) -> Vec<u32> {
+		let mut rng = rand::thread_rng();
+		*self = Survival::new();
+
+		let (min, max) = INITIAL_POPULATION;
+		let mut kinds = Vec::new();
+		for _ in 0.. rng.gen_range(min..max) {
+			kinds.push(self.pick_kind());
+		}
+		kinds
+	}
+
+	/// Advances the run by one update and returns the kinds of zombies to
+	/// spawn this frame.
+	pub fn update(&mut self, alive: usize) -> Vec<u32> {
+		self.frames += 1;
+		if self.powerup_timer > 0 {
+			self.powerup_timer -= 1;
+		}
+
+		let mut kinds = Vec::new();
+		self.spawn_timer -= 1;
+		if self.spawn_timer > 0 {
+			return kinds;
+		}
+
+		let stage = self.stage();
+		self.spawn_timer = START_INTERVAL - (stage * INTERVAL_STEP);
+		if self.spawn_timer < MIN_INTERVAL {
+			self.spawn_timer = MIN_INTERVAL;
+		}
+		for _ in 0.. 1 + (stage / 2) {
+			if alive + kinds.len() >= MAX_ENEMIES as usize {
+				break;
+			}
+			kinds.push(self.pick_kind());
+		}
+		kinds
+	}
+
+	/// Whether a new powerup should be dropped with `on_map` lying around.
+	pub fn powerup_due(&mut self, on_map: usize) -> bool {
+		if self.powerup_timer > 0 || on_map >= MAX_POWERUPS {
+			return false;
+		}
+		self.powerup_timer = POWERUP_INTERVAL;
+		true
+	}
+
+	pub fn record_kills(&mut self, kills: u32) {
+		self.kills += kills;
+	}
+
+	pub fn stage(&self) -> i32 {
+		self.frames / STAGE_FRAMES
+	}
+
+	pub fn seconds(&self) -> i32 {
+		self.frames / FRAMES_PER_SECOND
+	}
+
+	pub fn get_kills(&self) -> u32 {
+		self.kills
+	}
+
+	pub fn score(&self) -> i32 {
+		(self.seconds() * SECOND_SCORE) + (self.kills as i32 * KILL_SCORE)
+	}
+
+	/// The run so far, as it would go on the leaderboard.
+	pub fn entry(&self) -> leaderboard::Entry {
+		leaderboard::Entry {
+			score:  self.score(),
+			frames: self.frames,
+			kills:  self.kills
+		}
+	}
+
+	fn pick_kind(&self) -> u32 {
+		let mut rng = rand::thread_rng();
+		let last = STAGE_MIX.len() - 1;
+		let mix = STAGE_MIX[if (self.stage() as usize) < last { self.stage() as usize } else { last }];
+		let mut roll = rng.gen_range(0u32..10u32);
+		for (i, chance) in mix.iter().enumerate() {
+			if roll < *chance {
+				return i as u32 + 1;
+			}
+			roll -= chance;
+		}
+		1
+	}
+}
@@ -1,6 +1,8 @@
 use game::collisions::Rectangle;
+use game::enemies;
 use game::sprite;
 use game::graphics;
+use game::health;
 use game::map;
 
 use game::units;
@@ -14,6 +16,57 @@ const BEAR_TRAP_FRAME: units::Tile = units::Tile(0);
 // Animation frames 
 const BEAR_TRAP_CLOSED_FRAME: units::Tile = units::Tile(1);
 
+// spikes and barricades are drawn from the cave tile sheet
+const SPIKES_FRAME: (units::Tile, units::Tile) = (units::Tile(2), units::Tile(3));
+const BARRICADE_FRAME: (units::Tile, units::Tile) = (units::Tile(1), units::Tile(0));
+
+// the rest (16x16) are drawn from the symbol sheet, centred on their tile
+static SYMBOL_SHEET: &str = "assets/base/Npc/NpcSym.bmp";
+const LANDMINE_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(0), units::HalfTile(6));
+const LANDMINE_LIT_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(1), units::HalfTile(6));
+const TRIPWIRE_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(8), units::HalfTile(4));
+const PIT_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(12), units::HalfTile(0));
+const SPRING_FRAME: (units::HalfTile, units::HalfTile) = (units::HalfTile(14), units::HalfTile(2));
+const SYMBOL_OFFSET: units::Game = units::Game(8.0);
+
+// trap kinds
+pub const BEAR_TRAP: i32 = 1;
+pub const SPIKES:    i32 = 2;
+pub const BARRICADE: i32 = 3;
+pub const LANDMINE:  i32 = 4;
+pub const TRIPWIRE:  i32 = 5;
+pub const PIT:       i32 = 6;
+pub const SPRING:    i32 = 7;
+
+// spikes stay armed for a few zombies, resetting between each
+static SPIKE_USES:          u32 = 4;
+static SPIKE_REARM_FRAMES:  i32 = 45;
+
+static BARRICADE_HP:                  u32 = 8;
+static BARRICADE_INVULNERABLE_FRAMES: i32 = 30;
+
+// landmines go off a moment after being stepped on
+static LANDMINE_FUSE_FRAMES: i32 = 45;
+// tripwires, pits and springs reset themselves after going off
+static TRIPWIRE_RESET_FRAMES: i32 = 120;
+static PIT_HOLD_FRAMES:       i32 = 240;
+static PIT_RESET_FRAMES:      i32 = 90;
+static SPRING_RESET_FRAMES:   i32 = 60;
+
+/// A trap kind by the name level data refers to it by.
+pub fn kind_named(name: &str) -> Option<i32> {
+	match name {
+		"bear-trap" => Some(BEAR_TRAP),
+		"spikes"    => Some(SPIKES),
+		"barricade" => Some(BARRICADE),
+		"landmine"  => Some(LANDMINE),
+		"tripwire"  => Some(TRIPWIRE),
+		"pit"       => Some(PIT),
+		"spring"    => Some(SPRING),
+		_           => None
+	}
+}
+
 pub trait Trap {
 	fn draw(&self, display: &mut graphics::Graphics);
 	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map);
@@ -23,12 +76,80 @@ pub trait Trap {
 	fn set_timer(&mut self);
 	fn get_map_x(&self) -> units::Game;
 	fn get_map_y(&self) -> units::Game;
+	/// Springs the trap on whatever stepped in it. Returns whether the trap
+	/// is used up and should be removed from the map.
+	fn trigger(&mut self) -> bool;
+	/// Whether stepping in the trap right now would spring it.
+	fn is_armed(&self) -> bool;
+	/// Marks the trap as put down by the player, so it won't go off on them.
+	fn set_placed(&mut self);
+	fn is_placed(&self) -> bool;
+	/// Whether zombies are stopped by the trap rather than walking into it.
+	fn blocks_zombies(&self) -> bool;
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult;
+	/// Who can set the trap off.
+	fn catches_player(&self) -> bool;
+	fn catches_zombies(&self) -> bool;
+	/// Whether a lit fuse has burnt down and the trap should explode now.
+	fn is_detonating(&self) -> bool;
+	/// Keeps hold of a zombie that fell in until `release` lets it go.
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>);
+	/// Hands back the held zombie once it has been held long enough.
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>>;
 }
 
 pub struct BearTrap {
 	character: Character, 
 	animation_sprite: Vec<Box<dyn sprite::Updatable<units::Game>>>, 
-	animation_timer: i32
+	animation_timer: i32,
+	placed: bool
+}
+
+/// Reusable spikes that hurt a few zombies before they wear out.
+pub struct Spikes {
+	character: Character, 
+	uses: u32,
+	rearm_timer: i32,
+	placed: bool
+}
+
+/// A wall the player can put down. Zombies can't get past it and batter
+/// it down instead.
+pub struct Barricade {
+	character: Character, 
+	placed: bool
+}
+
+/// Blows up shortly after being stepped on, hurting everything nearby.
+pub struct Landmine {
+	character: Character, 
+	lit_sprite: Box<dyn sprite::Updatable<units::Game>>,
+	fuse_timer: i32,
+	lit: bool,
+	placed: bool
+}
+
+/// Makes a racket that every zombie within earshot comes to investigate.
+pub struct Tripwire {
+	character: Character, 
+	reset_timer: i32,
+	placed: bool
+}
+
+/// Swallows a zombie and holds on to it for a while.
+pub struct Pit {
+	character: Character, 
+	held: Option<Box<dyn enemies::Zombie>>,
+	hold_timer: i32,
+	reset_timer: i32,
+	placed: bool
+}
+
+/// Flings the player the way they were heading.
+pub struct Spring {
+	character: Character, 
+	reset_timer: i32,
+	placed: bool
 }
 
 impl BearTrap {
@@ -40,7 +161,8 @@ impl BearTrap {
 		let mut new_trap = BearTrap { 
 			character: common::Character::new(x, y), 
 			animation_sprite: animation,
-			animation_timer: 0
+			animation_timer: 0,
+			placed: false
 		};
 
 		for motion in sprite::MOTIONS.iter() {
@@ -104,7 +226,7 @@ impl Trap for BearTrap {
 	}
 
 	fn get_type(&self) -> i32 {
-		1
+		BEAR_TRAP
 	}
 
 	fn is_finished(&mut self) -> bool {
@@ -125,4 +247,804 @@ impl Trap for BearTrap {
 	fn get_map_y(&self) -> units::Game {
 		self.character.map_center_y()
 	}
-}
\ No newline at end of file
+
+	fn trigger(&mut self) -> bool {
+		self.set_timer();
+		true
+	}
+
+	fn is_armed(&self) -> bool {
+		true
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		health::DamageResult::Ignored
+	}
+
+	fn catches_player(&self) -> bool {
+		true
+	}
+
+	fn catches_zombies(&self) -> bool {
+		true
+	}
+
+	fn is_detonating(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		None
+	}
+}
+
+impl Spikes {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Spikes {
+
+		let mut new_trap = Spikes { 
+			character: common::Character::new(x, y), 
+			uses: SPIKE_USES,
+			rearm_timer: 0,
+			placed: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_trap.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_trap
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/Stage/PrtCave.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			SPIKES_FRAME,
+			(units::Tile(1), units::Tile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Trap for Spikes {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		// retracted spikes flicker until they are armed again
+		if self.rearm_timer % 10 >= 5 {
+			return;
+		}
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		if self.rearm_timer > 0 {
+			self.rearm_timer -= 1;
+		}
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn get_type(&self) -> i32 {
+		SPIKES
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		self.rearm_timer = SPIKE_REARM_FRAMES;
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn trigger(&mut self) -> bool {
+		self.uses -= 1;
+		self.set_timer();
+		self.uses == 0
+	}
+
+	fn is_armed(&self) -> bool {
+		self.rearm_timer == 0 && self.uses > 0
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		health::DamageResult::Ignored
+	}
+
+	fn catches_player(&self) -> bool {
+		true
+	}
+
+	fn catches_zombies(&self) -> bool {
+		true
+	}
+
+	fn is_detonating(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		None
+	}
+}
+
+impl Barricade {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Barricade {
+
+		let mut new_trap = Barricade { 
+			character: common::Character::new(x, y), 
+			placed: false
+		};
+		new_trap.character.health = health::Health::new(BARRICADE_HP, BARRICADE_INVULNERABLE_FRAMES);
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_trap.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_trap
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let asset_path = "assets/base/Stage/PrtCave.bmp".to_string();
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			BARRICADE_FRAME,
+			(units::Tile(1), units::Tile(1)),
+			asset_path
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Trap for Barricade {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		self.character.health.update();
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn get_type(&self) -> i32 {
+		BARRICADE
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn trigger(&mut self) -> bool {
+		false
+	}
+
+	fn is_armed(&self) -> bool {
+		false
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		true
+	}
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		self.character.health.take_damage(&damage)
+	}
+
+	fn catches_player(&self) -> bool {
+		false
+	}
+
+	fn catches_zombies(&self) -> bool {
+		false
+	}
+
+	fn is_detonating(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		None
+	}
+}
+
+impl Landmine {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Landmine {
+
+		let lit_sprite = Box::new( sprite::Sprite::new(
+			graphics,
+			LANDMINE_LIT_FRAME,
+			(units::HalfTile(1), units::HalfTile(1)),
+			SYMBOL_SHEET.to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		let mut new_trap = Landmine { 
+			character: common::Character::new(x, y), 
+			lit_sprite,
+			fuse_timer: 0,
+			lit: false,
+			placed: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_trap.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_trap
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			LANDMINE_FRAME,
+			(units::HalfTile(1), units::HalfTile(1)),
+			SYMBOL_SHEET.to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Trap for Landmine {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		let position = (correction_x + SYMBOL_OFFSET, correction_y + SYMBOL_OFFSET);
+		// blink faster as the fuse burns down
+		if self.lit && (self.fuse_timer / 4) % 2 == 0 {
+			self.lit_sprite.draw(display, position);
+		} else {
+			self.character.sprites.get(&self.character.movement).unwrap().draw(display, position);
+		}
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		if self.lit && self.fuse_timer > 0 {
+			self.fuse_timer -= 1;
+		}
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn get_type(&self) -> i32 {
+		LANDMINE
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		self.lit = true;
+		self.fuse_timer = LANDMINE_FUSE_FRAMES;
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	/// Lights the fuse, the mine is removed once it goes off.
+	fn trigger(&mut self) -> bool {
+		self.set_timer();
+		false
+	}
+
+	fn is_armed(&self) -> bool {
+		!self.lit
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		health::DamageResult::Ignored
+	}
+
+	fn catches_player(&self) -> bool {
+		true
+	}
+
+	fn catches_zombies(&self) -> bool {
+		true
+	}
+
+	fn is_detonating(&self) -> bool {
+		self.lit && self.fuse_timer == 0
+	}
+
+	#[allow(unused_variables)]
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		None
+	}
+}
+
+impl Tripwire {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Tripwire {
+
+		let mut new_trap = Tripwire { 
+			character: common::Character::new(x, y), 
+			reset_timer: 0,
+			placed: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_trap.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_trap
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			TRIPWIRE_FRAME,
+			(units::HalfTile(1), units::HalfTile(1)),
+			SYMBOL_SHEET.to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Trap for Tripwire {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x + SYMBOL_OFFSET, correction_y + SYMBOL_OFFSET));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		if self.reset_timer > 0 {
+			self.reset_timer -= 1;
+		}
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn get_type(&self) -> i32 {
+		TRIPWIRE
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		self.reset_timer = TRIPWIRE_RESET_FRAMES;
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn trigger(&mut self) -> bool {
+		self.set_timer();
+		false
+	}
+
+	fn is_armed(&self) -> bool {
+		self.reset_timer == 0
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		health::DamageResult::Ignored
+	}
+
+	/// Only the player is clumsy enough to catch the wire.
+	fn catches_player(&self) -> bool {
+		true
+	}
+
+	fn catches_zombies(&self) -> bool {
+		false
+	}
+
+	fn is_detonating(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		None
+	}
+}
+
+impl Pit {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Pit {
+
+		let mut new_trap = Pit { 
+			character: common::Character::new(x, y), 
+			held: None,
+			hold_timer: 0,
+			reset_timer: 0,
+			placed: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_trap.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_trap
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			PIT_FRAME,
+			(units::HalfTile(1), units::HalfTile(1)),
+			SYMBOL_SHEET.to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Trap for Pit {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x + SYMBOL_OFFSET, correction_y + SYMBOL_OFFSET));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		if self.hold_timer > 0 {
+			self.hold_timer -= 1;
+		} else if self.held.is_none() && self.reset_timer > 0 {
+			self.reset_timer -= 1;
+		}
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn get_type(&self) -> i32 {
+		PIT
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		self.hold_timer = PIT_HOLD_FRAMES;
+		self.reset_timer = PIT_RESET_FRAMES;
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn trigger(&mut self) -> bool {
+		self.set_timer();
+		false
+	}
+
+	fn is_armed(&self) -> bool {
+		self.held.is_none() && self.reset_timer == 0
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		health::DamageResult::Ignored
+	}
+
+	/// The player knows to walk around it.
+	fn catches_player(&self) -> bool {
+		false
+	}
+
+	fn catches_zombies(&self) -> bool {
+		true
+	}
+
+	fn is_detonating(&self) -> bool {
+		false
+	}
+
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		self.held = Some(zombie);
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		if self.hold_timer == 0 {
+			self.held.take()
+		} else {
+			None
+		}
+	}
+}
+
+impl Spring {
+	pub fn new(graphics: &mut graphics::Graphics,
+	           x: units::Game, y: units::Game) -> Spring {
+
+		let mut new_trap = Spring { 
+			character: common::Character::new(x, y), 
+			reset_timer: 0,
+			placed: false
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				new_trap.load_sprite(graphics, (*motion, *facing));
+			}
+		}
+
+		new_trap
+	}
+
+	pub fn load_sprite(&mut self, 
+	               display: &mut graphics::Graphics,
+	               movement: (sprite::Motion, sprite::Facing)) {
+
+		let loaded_sprite = Box::new( sprite::Sprite::new(
+			display,
+			SPRING_FRAME,
+			(units::HalfTile(1), units::HalfTile(1)),
+			SYMBOL_SHEET.to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		self.character.sprites.insert(movement, loaded_sprite);
+	}
+}
+
+impl Trap for Spring {
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.get_map_x() % common::SCREEN_CORRECTION;
+		let correction_y = self.character.get_map_y() % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x + SYMBOL_OFFSET, correction_y + SYMBOL_OFFSET));
+	}
+
+	#[allow(unused_variables)]
+	fn update(&mut self, elapsed_time: units::Millis, map: &map::Map) {
+		if self.reset_timer > 0 {
+			self.reset_timer -= 1;
+		}
+	}
+
+	fn damage_rectangle(&self) -> Rectangle {
+		self.character.damage_rectangle()
+	}
+
+	fn get_type(&self) -> i32 {
+		SPRING
+	}
+
+	fn is_finished(&mut self) -> bool {
+		true
+	}
+
+	fn set_timer(&mut self) {
+		self.reset_timer = SPRING_RESET_FRAMES;
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+
+	fn trigger(&mut self) -> bool {
+		self.set_timer();
+		false
+	}
+
+	fn is_armed(&self) -> bool {
+		self.reset_timer == 0
+	}
+
+	fn set_placed(&mut self) {
+		self.placed = true;
+	}
+
+	fn is_placed(&self) -> bool {
+		self.placed
+	}
+
+	fn blocks_zombies(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		health::DamageResult::Ignored
+	}
+
+	fn catches_player(&self) -> bool {
+		true
+	}
+
+	fn catches_zombies(&self) -> bool {
+		false
+	}
+
+	fn is_detonating(&self) -> bool {
+		false
+	}
+
+	#[allow(unused_variables)]
+	fn hold(&mut self, zombie: Box<dyn enemies::Zombie>) {
+		
+	}
+
+	fn release(&mut self) -> Option<Box<dyn enemies::Zombie>> {
+		None
+	}
+}
@@ -1,12 +1,57 @@
 use game::collisions::Rectangle;
+use game::datafile;
+use game::sprite;
 use game::graphics;
+use game::common;
+use game::common::Character;
+use game::map;
+use game::health;
 
 use game::units;
+use game::units::AsGame;
+
+use sdl2::rect;
+
+// where the vehicles that can turn up on a level are defined
+pub static VEHICLE_DATA: &str = "assets/vehicles.txt";
+
+// reaching this column of the map gets the player away
+pub static EXTRACTION_X:          units::Tile = units::Tile(58);
+// after a crash, another one won't count for this long (in updates)
+pub static CRASH_RECOVERY_FRAMES: i32 = 45;
+
+// how much punishment the wreck takes before a part is knocked loose, unless
+// the data file says otherwise
+static DEFAULT_HEALTH:       u32 = 10;
+static INVULNERABLE_FRAMES:  i32 = 30;
+// health meter drawn above the wreck (in pixels)
+static METER_HEIGHT:         u32 = 4;
+static METER_OFFSET:         f64 = -8.0;
+static METER_COLOR:          (u8, u8, u8) = (220, 120, 40);
+
+// road handling (in game units per update), quick along the road but can
+// only turn while moving
+static ROAD_ACCELERATION:  f64 = 0.15;
+static ROAD_MAX_SPEED:     f64 = 5.0;
+static ROAD_STEER_SPEED:   f64 = 2.0;
+static ROAD_FRICTION:      f64 = 0.95;
+
+// air handling, hovers low and slow while taking off, then flies over
+// everything
+static TAKEOFF_FRAMES:     i32 = 120;
+static AIR_ACCELERATION:   f64 = 0.1;
+static TAKEOFF_SPEED:      f64 = 1.5;
+static AIR_MAX_SPEED:      f64 = 4.0;
+static AIR_DRAG:           f64 = 0.97;
+// how far it can fly past the top and bottom of the map
+static CEILING:            units::Tile = units::Tile(0);
+static FLOOR:              units::Tile = units::Tile(57);
+
+static BOUNCE:             f64 = 0.5;
 
 pub trait Vehicle {
 	fn damage_rectangle(&self) -> Rectangle;
 	fn update(&mut self, elapsed_time: units::Millis);
-	fn update_for_cinematic(&mut self);
 	fn draw(&self, display: &mut graphics::Graphics);
 	fn add_part(&mut self, part_num: u32);
 	fn is_built(&self) -> bool;
@@ -15,14 +60,552 @@ pub trait Vehicle {
 	fn get_map_x(&self) -> units::Game;
 	fn get_map_y(&self) -> units::Game;
 	fn get_type(&self) -> i32;
+
+	/// How many parts it takes to finish the vehicle.
+	fn part_count(&self) -> u32;
+	/// A loose part `index` (counting from zero) for the vehicle at `x`, `y`.
+	fn make_part(&self, display: &mut graphics::Graphics, index: u32, x: units::Game, y: units::Game) -> Box<dyn Part>;
+	fn installed_parts(&self) -> u32;
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult;
+	/// Pulls off the last part installed, returning its part type, and
+	/// patches the wreck back up to full health.
+	fn knock_part_loose(&mut self) -> Option<u32>;
+	fn get_health(&self) -> u32;
+	fn get_max_health(&self) -> u32;
+
+	/// Takes the player aboard, handing control of the vehicle over to them.
+	fn board(&mut self);
+	fn is_boarded(&self) -> bool;
+	/// Which way the driver wants to go, each direction being -1, 0 or 1.
+	fn steer(&mut self, x_direction: i32, y_direction: i32);
+	/// Moves a boarded vehicle, returning whether it crashed.
+	fn drive(&mut self, elapsed_time: units::Millis, map: &map::Map) -> bool;
+	/// Knocks the vehicle back, returning whether the crash counts (it
+	/// doesn't while still recovering from the last one).
+	fn crash(&mut self) -> bool;
+	/// Whether the vehicle knocks down zombies it runs into.
+	fn rams_zombies(&self) -> bool;
+	/// Whether zombies touching the vehicle make it crash.
+	fn is_vulnerable(&self) -> bool;
+	/// Whether the vehicle has made it to the extraction edge.
+	fn has_escaped(&self) -> bool;
 }
 
 pub trait Part {
 	fn draw(&self, display: &mut graphics::Graphics);
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game);
 	fn damage_rectangle(&self) -> Rectangle;
 	fn part_type(&self) -> u32;
+	fn set_position(&mut self, x: units::Game, y: units::Game);
 	fn get_x(&self) -> units::Game;
 	fn get_y(&self) -> units::Game;
 	fn get_map_x(&self) -> units::Game;
 	fn get_map_y(&self) -> units::Game;
-}
\ No newline at end of file
+}
+
+/// How a vehicle drives once boarded.
+#[derive(PartialEq,Eq,Clone,Copy)]
+pub enum Handling {
+	/// Rams zombies but crashes into walls.
+	Road,
+	/// Has to dodge zombies while taking off, then flies over walls.
+	Air
+}
+
+/// `x`, `y`, width and height, either on a sprite sheet or relative to
+/// whatever it belongs to.
+pub type Area = (units::Game, units::Game, units::Game, units::Game);
+
+#[derive(Clone)]
+pub struct PartDefinition {
+	pub name:       String,
+	pub icon:       Area,
+	pub pickup:     Area,
+	pub overlay:    Area,
+	pub overlay_at: (units::Game, units::Game)
+}
+
+/// A vehicle as described in the vehicle data file.
+#[derive(Clone)]
+pub struct VehicleDefinition {
+	pub name:     String,
+	pub sheet:    String,
+	pub handling: Handling,
+	pub base:     Area,
+	pub base_at:  (units::Game, units::Game),
+	pub damage:   Area,
+	pub health:   u32,
+	pub parts:    Vec<PartDefinition>
+}
+
+/// Reads every vehicle from the data file at `path`.
+///
+/// The file is a list of `keyword values...` lines, see the data file
+/// itself for the keywords. Anything after a `#` is ignored. Like a
+/// missing sprite, a broken file stops the game straight away with a
+/// message saying what is wrong.
+pub fn load_vehicles(path: &str) -> Vec<VehicleDefinition> {
+	let mut vehicles: Vec<VehicleDefinition> = Vec::new();
+	datafile::read_lines(path, "vehicles", |line| {
+		let (keyword, rest) = (line.keyword, &line.rest);
+		if keyword == "vehicle" {
+			vehicles.push(VehicleDefinition {
+				name:     rest.join(" "),
+				sheet:    String::new(),
+				handling: Handling::Road,
+				base:     (units::Game(0.0), units::Game(0.0), units::Game(0.0), units::Game(0.0)),
+				base_at:  (units::Game(0.0), units::Game(0.0)),
+				damage:   (units::Game(0.0), units::Game(0.0), units::Game(0.0), units::Game(0.0)),
+				health:   DEFAULT_HEALTH,
+				parts:    Vec::new()
+			});
+			return;
+		}
+		let vehicle = line.block(&mut vehicles, "vehicle");
+		if keyword == "part" {
+			vehicle.parts.push(PartDefinition {
+				name:       rest.join(" "),
+				icon:       (units::Game(0.0), units::Game(0.0), units::Game(0.0), units::Game(0.0)),
+				pickup:     (units::Game(0.0), units::Game(0.0), units::Game(0.0), units::Game(0.0)),
+				overlay:    (units::Game(0.0), units::Game(0.0), units::Game(0.0), units::Game(0.0)),
+				overlay_at: (units::Game(0.0), units::Game(0.0))
+			});
+			return;
+		}
+
+		let numbers: Vec<units::Game> = match keyword {
+			"sheet" | "handling" => Vec::new(),
+			_ => rest.iter().map(|word| match word.parse::<f64>() {
+				Ok(number) => units::Game(number),
+				Err(_)     => line.error("expected a number")
+			}).collect()
+		};
+		let expected = match keyword {
+			"base" | "overlay"          => 6,
+			"damage" | "icon" | "pickup" => 4,
+			"health"                     => 1,
+			_                            => 0
+		};
+		if numbers.len() != expected {
+			line.error("wrong number of values");
+		}
+
+		match keyword {
+			"sheet"    => { vehicle.sheet = rest.join(" "); },
+			"handling" => {
+				vehicle.handling = match rest.first() {
+					Some(&"road") => Handling::Road,
+					Some(&"air")  => Handling::Air,
+					_             => line.error("handling should be road or air")
+				};
+			},
+			"base" => {
+				vehicle.base = (numbers[0], numbers[1], numbers[2], numbers[3]);
+				vehicle.base_at = (numbers[4], numbers[5]);
+			},
+			"damage" => { vehicle.damage = (numbers[0], numbers[1], numbers[2], numbers[3]); },
+			"health" => {
+				let units::Game(health) = numbers[0];
+				vehicle.health = health as u32;
+			},
+			_ => {
+				let part = line.block(&mut vehicle.parts, "part");
+				match keyword {
+					"icon"    => { part.icon = (numbers[0], numbers[1], numbers[2], numbers[3]); },
+					"pickup"  => { part.pickup = (numbers[0], numbers[1], numbers[2], numbers[3]); },
+					"overlay" => {
+						part.overlay = (numbers[0], numbers[1], numbers[2], numbers[3]);
+						part.overlay_at = (numbers[4], numbers[5]);
+					},
+					_ => line.error("unknown keyword")
+				}
+			}
+		}
+	});
+
+	for vehicle in vehicles.iter() {
+		if vehicle.sheet.is_empty() {
+			panic!("{}: vehicle {} has no sheet", path, vehicle.name);
+		}
+		if is_unset(vehicle.base) {
+			panic!("{}: vehicle {} has no base", path, vehicle.name);
+		}
+		if is_unset(vehicle.damage) {
+			panic!("{}: vehicle {} has no damage area", path, vehicle.name);
+		}
+		if vehicle.parts.is_empty() {
+			panic!("{}: vehicle {} has no parts", path, vehicle.name);
+		}
+		for part in vehicle.parts.iter() {
+			if is_unset(part.icon) || is_unset(part.pickup) || is_unset(part.overlay) {
+				panic!("{}: part {} of vehicle {} needs an icon, pickup and overlay", path, part.name, vehicle.name);
+			}
+		}
+	}
+	if vehicles.is_empty() {
+		panic!("{}: no vehicles defined", path);
+	}
+	vehicles
+}
+
+/// Whether an area was never given a size in the data file.
+fn is_unset(area: Area) -> bool {
+	let (_, _, units::Game(width), units::Game(height)) = area;
+	width <= 0.0 || height <= 0.0
+}
+
+fn load_area(display: &mut graphics::Graphics, sheet: &str, area: Area) -> Box<dyn sprite::Updatable<units::Game>> {
+	let (x, y, width, height) = area;
+	Box::new( sprite::Sprite::new(
+		display,
+		(x, y),
+		(width, height),
+		String::from(sheet)
+	) ) as Box<dyn sprite::Updatable<_>>
+}
+
+/// Whether `rectangle` overlaps any wall on `map`.
+pub fn hits_wall(map: &map::Map, rectangle: &Rectangle) -> bool {
+	map.get_colliding_tiles(rectangle).iter().any(|tile| tile.tile_type == map::TileType::Wall)
+}
+
+/// Whether `rectangle` has reached the extraction edge.
+pub fn at_extraction(rectangle: &Rectangle) -> bool {
+	rectangle.right() >= EXTRACTION_X.to_game()
+}
+
+/// A vehicle built from its definition. The wreck is drawn first, then an
+/// overlay for each part installed so far.
+pub struct DataVehicle {
+	pub x: units::Game,
+	pub y: units::Game,
+	pub map_x: units::Game,
+	pub map_y: units::Game,
+
+	definition: VehicleDefinition,
+	kind:       i32,
+	base:       Box<dyn sprite::Updatable<units::Game>>,
+	overlays:   Vec<Box<dyn sprite::Updatable<units::Game>>>,
+	parts:      Vec<u32>,
+	health:     health::Health,
+
+	boarded:       bool,
+	steering:      (i32, i32),
+	velocity_x:    f64,
+	velocity_y:    f64,
+	takeoff_timer: i32,
+	crash_timer:   i32
+}
+
+pub struct DataPart {
+	character: Character,
+	part_type: u32,
+	pickup:    Area
+}
+
+impl DataVehicle {
+	/// Builds the wreck of vehicle `kind` (counting from zero) in `definitions`.
+	pub fn new(display: &mut graphics::Graphics,
+	           definitions: &[VehicleDefinition], kind: usize,
+	           x: units::Game, y: units::Game) -> DataVehicle {
+
+		let definition = definitions[kind].clone();
+		let base = load_area(display, &definition.sheet, definition.base);
+		let overlays = definition.parts.iter().map(|part| load_area(display, &definition.sheet, part.overlay)).collect();
+		let health = health::Health::new(definition.health, INVULNERABLE_FRAMES);
+
+		DataVehicle {
+			x, y,
+			map_x: x, map_y: y,
+
+			definition,
+			kind: kind as i32 + 1,
+			base,
+			overlays,
+			parts: Vec::new(),
+			health,
+
+			boarded: false,
+			steering: (0, 0),
+			velocity_x: 0.0,
+			velocity_y: 0.0,
+			takeoff_timer: TAKEOFF_FRAMES,
+			crash_timer: 0
+		}
+	}
+
+	fn drive_on_road(&mut self) {
+		let (x_direction, y_direction) = self.steering;
+		if x_direction == 0 {
+			self.velocity_x *= ROAD_FRICTION;
+		} else {
+			self.velocity_x += x_direction as f64 * ROAD_ACCELERATION;
+		}
+		self.velocity_x = self.velocity_x.max(-ROAD_MAX_SPEED).min(ROAD_MAX_SPEED);
+		self.velocity_y = y_direction as f64 * ROAD_STEER_SPEED * (self.velocity_x.abs() / ROAD_MAX_SPEED);
+	}
+
+	fn fly(&mut self) {
+		if self.takeoff_timer > 0 {
+			self.takeoff_timer -= 1;
+		}
+		let max_speed = if self.is_vulnerable() { TAKEOFF_SPEED } else { AIR_MAX_SPEED };
+		let (x_direction, y_direction) = self.steering;
+		self.velocity_x = (self.velocity_x * AIR_DRAG) + (x_direction as f64 * AIR_ACCELERATION);
+		self.velocity_y = (self.velocity_y * AIR_DRAG) + (y_direction as f64 * AIR_ACCELERATION);
+		self.velocity_x = self.velocity_x.max(-max_speed).min(max_speed);
+		self.velocity_y = self.velocity_y.max(-max_speed).min(max_speed);
+	}
+
+	/// Whether the vehicle is low enough to hit walls.
+	fn is_grounded(&self) -> bool {
+		self.definition.handling == Handling::Road || self.takeoff_timer > 0
+	}
+}
+
+impl Vehicle for DataVehicle {
+	fn damage_rectangle(&self) -> Rectangle {
+		let (x, y, width, height) = self.definition.damage;
+		Rectangle {
+			x: self.map_x + x, y: self.map_y + y,
+			width, height,
+		}
+	}
+
+	fn update(&mut self, elapsed_time: units::Millis) {
+		self.base.update(elapsed_time);
+		self.health.update();
+	}
+
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let (base_x, base_y) = self.definition.base_at;
+		self.base.draw(display, (self.x + base_x, self.y + base_y));
+		for (i, overlay) in self.overlays.iter().enumerate() {
+			if self.parts.contains(&(i as u32 + 1)) {
+				let (overlay_x, overlay_y) = self.definition.parts[i].overlay_at;
+				overlay.draw(display, (self.x + overlay_x, self.y + overlay_y));
+			}
+		}
+
+		if !self.boarded {
+			let (damage_x, damage_y, units::Game(width), _) = self.definition.damage;
+			let (units::Game(x), units::Game(y)) = (self.x + damage_x, self.y + damage_y);
+			let meter = rect::Rect::new(x as i32, (y + METER_OFFSET) as i32, width as u32, METER_HEIGHT);
+			display.draw_bar(self.health.get_current() as f64, self.health.get_max() as f64, meter, METER_COLOR);
+		}
+	}
+
+	fn add_part(&mut self, part_num: u32) {
+		if !self.parts.contains(&part_num) {
+			self.parts.push(part_num);
+		}
+	}
+
+	fn is_built(&self) -> bool {
+		self.parts.len() == self.definition.parts.len()
+	}
+
+	fn get_x(&self) -> units::Game {
+		self.x
+	}
+
+	fn get_y(&self) -> units::Game {
+		self.y
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.map_x
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.map_y
+	}
+
+	fn get_type(&self) -> i32 {
+		self.kind
+	}
+
+	fn part_count(&self) -> u32 {
+		self.definition.parts.len() as u32
+	}
+
+	fn make_part(&self, display: &mut graphics::Graphics, index: u32, x: units::Game, y: units::Game) -> Box<dyn Part> {
+		Box::new( DataPart::new(
+			display,
+			&self.definition.sheet,
+			&self.definition.parts[index as usize],
+			index + 1,
+			x,
+			y
+		) ) as Box<dyn Part>
+	}
+
+	fn installed_parts(&self) -> u32 {
+		self.parts.len() as u32
+	}
+
+	fn take_damage(&mut self, damage: health::Damage) -> health::DamageResult {
+		if self.boarded {
+			return health::DamageResult::Ignored;
+		}
+		self.health.take_damage(&damage)
+	}
+
+	fn knock_part_loose(&mut self) -> Option<u32> {
+		let max = self.health.get_max();
+		self.health.set_max(max);
+		self.health.start_invulnerability(INVULNERABLE_FRAMES);
+		self.parts.pop()
+	}
+
+	fn get_health(&self) -> u32 {
+		self.health.get_current()
+	}
+
+	fn get_max_health(&self) -> u32 {
+		self.health.get_max()
+	}
+
+	fn board(&mut self) {
+		self.boarded = true;
+	}
+
+	fn is_boarded(&self) -> bool {
+		self.boarded
+	}
+
+	fn steer(&mut self, x_direction: i32, y_direction: i32) {
+		self.steering = (x_direction, y_direction);
+	}
+
+	#[allow(unused_variables)]
+	fn drive(&mut self, elapsed_time: units::Millis, map: &map::Map) -> bool {
+		if self.crash_timer > 0 {
+			self.crash_timer -= 1;
+		}
+		match self.definition.handling {
+			Handling::Road => self.drive_on_road(),
+			Handling::Air  => self.fly()
+		}
+
+		let (last_x, last_y) = (self.map_x, self.map_y);
+		self.map_x = self.map_x + units::Game(self.velocity_x);
+		self.map_y = self.map_y + units::Game(self.velocity_y);
+
+		let mut crashed = false;
+		if self.is_grounded() {
+			if !self.has_escaped() && hits_wall(map, &self.damage_rectangle()) {
+				self.map_x = last_x;
+				self.map_y = last_y;
+				crashed = self.crash();
+			}
+		} else {
+			let ceiling = CEILING.to_game();
+			let floor = FLOOR.to_game();
+			if self.map_y < ceiling { self.map_y = ceiling; }
+			if self.map_y > floor { self.map_y = floor; }
+			if self.map_x < units::Game(0.0) { self.map_x = units::Game(0.0); }
+		}
+
+		self.x = self.map_x % common::SCREEN_CORRECTION;
+		self.y = self.map_y % common::SCREEN_CORRECTION;
+		crashed
+	}
+
+	fn crash(&mut self) -> bool {
+		self.velocity_x = -self.velocity_x * BOUNCE;
+		self.velocity_y = match self.definition.handling {
+			Handling::Road => 0.0,
+			Handling::Air  => -self.velocity_y * BOUNCE
+		};
+		if self.crash_timer > 0 {
+			false
+		} else {
+			self.crash_timer = CRASH_RECOVERY_FRAMES;
+			true
+		}
+	}
+
+	fn rams_zombies(&self) -> bool {
+		self.boarded && self.definition.handling == Handling::Road
+	}
+
+	fn is_vulnerable(&self) -> bool {
+		self.boarded && self.definition.handling == Handling::Air && self.takeoff_timer > 0
+	}
+
+	fn has_escaped(&self) -> bool {
+		at_extraction(&self.damage_rectangle())
+	}
+}
+
+impl DataPart {
+	pub fn new(display: &mut graphics::Graphics,
+	           sheet: &str, definition: &PartDefinition, part_type: u32,
+	           x: units::Game, y: units::Game) -> DataPart {
+
+		let mut new_part = DataPart {
+			character: Character::new(x, y),
+			part_type,
+			pickup: definition.pickup
+		};
+
+		for motion in sprite::MOTIONS.iter() {
+			for facing in sprite::FACINGS.iter() {
+				let loaded_sprite = load_area(display, sheet, definition.icon);
+				new_part.character.sprites.insert((*motion, *facing), loaded_sprite);
+			}
+		}
+
+		new_part
+	}
+}
+
+impl Part for DataPart {
+	fn damage_rectangle(&self) -> Rectangle {
+		let (x, y, width, height) = self.pickup;
+		Rectangle {
+			x: self.character.x + x, y: self.character.y + y,
+			width, height,
+		}
+	}
+
+	fn draw(&self, display: &mut graphics::Graphics) {
+		let correction_x = self.character.map_x % common::SCREEN_CORRECTION;
+		let correction_y = self.character.map_y % common::SCREEN_CORRECTION;
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (correction_x, correction_y));
+	}
+
+	fn part_type(&self) -> u32 {
+		self.part_type
+	}
+
+	fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.character.sprites.get(&self.character.movement).unwrap().draw(display, (x, y));
+	}
+
+	/// Moves the part to `x`, `y` on the map, e.g. when the player drops it.
+	fn set_position(&mut self, x: units::Game, y: units::Game) {
+		self.character.x = x;
+		self.character.y = y;
+		self.character.map_x = x;
+		self.character.map_y = y;
+	}
+
+	fn get_x(&self) -> units::Game {
+		self.character.center_x()
+	}
+
+	fn get_y(&self) -> units::Game {
+		self.character.center_y()
+	}
+
+	fn get_map_x(&self) -> units::Game {
+		self.character.map_center_x()
+	}
+
+	fn get_map_y(&self) -> units::Game {
+		self.character.map_center_y()
+	}
+}
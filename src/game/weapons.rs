@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use game::graphics;
+use game::sprite;
+
+use game::units;
+use game::units::AsGame;
+
+// weapon kinds
+pub const CRICKET_BAT: u32 = 1;
+pub const PISTOL:      u32 = 2;
+pub const MACHINE_GUN: u32 = 3;
+
+// stats: damage, range, frames between attacks, ammo (or durability for melee)
+static BAT_STATS:         (u32, units::Tile, i32, u32) = (1, units::Tile(1), 20, 5);
+static PISTOL_STATS:      (u32, units::Tile, i32, u32) = (3, units::Tile(8), 20, 12);
+static MACHINE_GUN_STATS: (u32, units::Tile, i32, u32) = (1, units::Tile(10), 6, 60);
+
+// HUD icons (16x16) on the weapon icon sheet
+static BAT_ICON:         units::HalfTile = units::HalfTile(9);
+static PISTOL_ICON:      units::HalfTile = units::HalfTile(2);
+static MACHINE_GUN_ICON: units::HalfTile = units::HalfTile(4);
+
+// held weapon sprites (24x16) on the arms sheet, one column per weapon
+static ARMS_WIDTH:       f64 = 24.0;
+static ARMS_HEIGHT:      f64 = 16.0;
+static PISTOL_ARMS:      f64 = 2.0;
+static MACHINE_GUN_ARMS: f64 = 4.0;
+
+/// What happens when a weapon is used.
+pub enum Attack {
+	/// Hits everything within `range` in front of the player.
+	Swing(u32, units::Game),
+	/// Fires a bullet that travels up to `range`.
+	Shot(u32, units::Game)
+}
+
+/// Something the player can fight with.
+///
+/// Guns use up a round per shot and can be topped up by picking up the same
+/// weapon again. Melee weapons instead wear down each time a swing connects
+/// and break once their durability runs out.
+pub struct Weapon {
+	kind:           u32,
+	damage:         u32,
+	range:          units::Tile,
+	cooldown:       i32,
+	cooldown_timer: i32,
+	ammo:           u32,
+	max_ammo:       u32,
+	icon:           Box<dyn sprite::Updatable<units::Game>>,
+	arms_sprites:   HashMap<sprite::Facing, Box<dyn sprite::Updatable<units::Game>>>
+}
+
+/// Damage, range, frames between attacks and ammo for weapon `kind`.
+pub fn stats(kind: u32) -> (u32, units::Tile, i32, u32) {
+	match kind {
+		CRICKET_BAT => BAT_STATS,
+		PISTOL      => PISTOL_STATS,
+		_           => MACHINE_GUN_STATS
+	}
+}
+
+impl Weapon {
+	pub fn new(graphics: &mut graphics::Graphics, kind: u32) -> Weapon {
+		let (damage, range, cooldown, ammo) = stats(kind);
+		let (icon_frame, arms_column) = match kind {
+			CRICKET_BAT => (BAT_ICON, None),
+			PISTOL      => (PISTOL_ICON, Some(PISTOL_ARMS)),
+			_           => (MACHINE_GUN_ICON, Some(MACHINE_GUN_ARMS))
+		};
+
+		let icon = Box::new( sprite::Sprite::new(
+			graphics,
+			(icon_frame, units::HalfTile(0)),
+			(units::HalfTile(1), units::HalfTile(1)),
+			"assets/base/ArmsImage.bmp".to_string()
+		) ) as Box<dyn sprite::Updatable<_>>;
+
+		// the bat is drawn as part of the player's sprite
+		let mut arms_sprites = HashMap::<sprite::Facing, Box<dyn sprite::Updatable<_>>>::new();
+		if let Some(column) = arms_column {
+			for facing in sprite::FACINGS.iter() {
+				let row = match *facing {
+					sprite::Facing::West => 0.0,
+					sprite::Facing::East => 1.0
+				};
+				arms_sprites.insert(*facing, Box::new( sprite::Sprite::new(
+					graphics,
+					(units::Game(column * ARMS_WIDTH), units::Game(row * ARMS_HEIGHT)),
+					(units::Game(ARMS_WIDTH), units::Game(ARMS_HEIGHT)),
+					"assets/base/Arms.bmp".to_string()
+				) ) as Box<dyn sprite::Updatable<_>>);
+			}
+		}
+
+		Weapon {
+			kind,
+			damage,
+			range,
+			cooldown,
+			cooldown_timer: 0,
+			ammo,
+			max_ammo:       ammo,
+			icon,
+			arms_sprites
+		}
+	}
+
+	/// Counts down the time until the weapon can be used again.
+	pub fn update(&mut self) {
+		if self.cooldown_timer > 0 {
+			self.cooldown_timer -= 1;
+		}
+	}
+
+	/// Uses the weapon if it is ready, spending a round for guns.
+	pub fn attack(&mut self) -> Option<Attack> {
+		if self.cooldown_timer > 0 || self.ammo == 0 {
+			return None;
+		}
+		self.cooldown_timer = self.cooldown;
+
+		if self.is_melee() {
+			Some(Attack::Swing(self.damage, self.range.to_game()))
+		} else {
+			self.ammo -= 1;
+			Some(Attack::Shot(self.damage, self.range.to_game()))
+		}
+	}
+
+	/// Wears a melee weapon down after a swing connects.
+	pub fn wear(&mut self) {
+		if self.is_melee() && self.ammo > 0 {
+			self.ammo -= 1;
+		}
+	}
+
+	pub fn refill(&mut self) {
+		self.ammo = self.max_ammo;
+	}
+
+	pub fn add_ammo(&mut self, ammo: u32) {
+		self.ammo = if self.ammo + ammo > self.max_ammo { self.max_ammo } else { self.ammo + ammo };
+	}
+
+	/// A melee weapon with no durability left.
+	pub fn is_broken(&self) -> bool {
+		self.is_melee() && self.ammo == 0
+	}
+
+	pub fn is_melee(&self) -> bool {
+		self.kind == CRICKET_BAT
+	}
+
+	pub fn get_type(&self) -> u32 {
+		self.kind
+	}
+
+	pub fn get_damage(&self) -> u32 {
+		self.damage
+	}
+
+	pub fn get_ammo(&self) -> u32 {
+		self.ammo
+	}
+
+	/// Draws the weapon in the player's hands, `x` and `y` being the player's
+	/// position on screen.
+	pub fn draw(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game, facing: sprite::Facing) {
+		let offset_x = match facing {
+			sprite::Facing::West => units::Game(-8.0),
+			sprite::Facing::East => units::Game(16.0)
+		};
+		if let Some(sprite) = self.arms_sprites.get(&facing) {
+			sprite.draw(display, (x + offset_x, y + units::Game(14.0)));
+		}
+	}
+
+	pub fn draw_icon(&self, display: &mut graphics::Graphics, x: units::Game, y: units::Game) {
+		self.icon.draw(display, (x, y));
+	}
+}